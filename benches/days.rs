@@ -0,0 +1,206 @@
+//! Benchmarks parsing and both parts of every day against its `input.txt`.
+//!
+//! Run with `cargo bench -- [days...] [--year <year>] [--time <ms>] [--output <path>]`. Results are
+//! printed as a table and written as JSON to `target/bench.json` by default.
+
+use std::{
+    env,
+    fmt::Write as _,
+    fs,
+    path::Path,
+    process,
+    time::{Duration, Instant},
+};
+
+use advent_of_code::{
+    solver::{self, Puzzle, Stage},
+    Result,
+};
+
+const MIN_ITERATIONS: usize = 3;
+
+struct Options {
+    /// The year to run, or every year if none is given.
+    year: Option<u16>,
+    days: Vec<u8>,
+    time: Duration,
+    output: String,
+}
+
+struct Measurement {
+    year: u16,
+    day: u8,
+    stage: &'static str,
+    samples: Vec<Duration>,
+}
+
+impl Measurement {
+    fn min(&self) -> Duration {
+        self.samples[0]
+    }
+
+    fn max(&self) -> Duration {
+        self.samples[self.samples.len() - 1]
+    }
+
+    fn median(&self) -> Duration {
+        self.samples[self.samples.len() / 2]
+    }
+
+    fn mean(&self) -> Duration {
+        self.samples.iter().sum::<Duration>() / self.samples.len() as u32
+    }
+}
+
+/// Runs `stage` until both `MIN_ITERATIONS` and the time budget are reached, returning sorted
+/// samples, or `None` if the day has no such stage.
+fn measure(time: Duration, stage: &mut Stage) -> Result<Option<Vec<Duration>>> {
+    let mut samples = Vec::new();
+    let start = Instant::now();
+
+    while samples.len() < MIN_ITERATIONS || start.elapsed() < time {
+        let sample_start = Instant::now();
+        let Some(result) = stage() else {
+            return Ok(None);
+        };
+        samples.push(sample_start.elapsed());
+        result?;
+    }
+
+    samples.sort_unstable();
+    Ok(Some(samples))
+}
+
+fn bench_puzzle(puzzle: &Puzzle, options: &Options) -> Result<Vec<Measurement>> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!(
+        "src/y{}/day{:02}/input.txt",
+        puzzle.year, puzzle.day
+    ));
+    let input = fs::read_to_string(path)?;
+
+    let mut measurements = Vec::new();
+    puzzle.stages(&input, &mut |stage, run| {
+        if let Some(samples) = measure(options.time, run)? {
+            measurements.push(Measurement {
+                year: puzzle.year,
+                day: puzzle.day,
+                stage,
+                samples,
+            });
+        }
+        Ok(())
+    })?;
+
+    Ok(measurements)
+}
+
+fn bench_puzzles(options: &Options) -> Result<Vec<Measurement>> {
+    let mut measurements = Vec::new();
+
+    for puzzle in solver::puzzles() {
+        if options.year.map_or(true, |year| year == puzzle.year)
+            && (options.days.is_empty() || options.days.contains(&puzzle.day))
+        {
+            measurements.extend(bench_puzzle(puzzle, options)?);
+        }
+    }
+
+    Ok(measurements)
+}
+
+fn to_json(measurements: &[Measurement]) -> String {
+    let mut json = String::from("[\n");
+
+    for (index, m) in measurements.iter().enumerate() {
+        let separator = if index + 1 < measurements.len() {
+            ","
+        } else {
+            ""
+        };
+        writeln!(
+            json,
+            r#"  {{"year": {}, "day": {}, "stage": "{}", "iterations": {}, "min_ns": {}, "median_ns": {}, "mean_ns": {}, "max_ns": {}}}{}"#,
+            m.year,
+            m.day,
+            m.stage,
+            m.samples.len(),
+            m.min().as_nanos(),
+            m.median().as_nanos(),
+            m.mean().as_nanos(),
+            m.max().as_nanos(),
+            separator,
+        )
+        .unwrap();
+    }
+
+    json.push_str("]\n");
+    json
+}
+
+fn parse_options(mut args: impl Iterator<Item = String>) -> std::result::Result<Options, String> {
+    let mut options = Options {
+        year: None,
+        days: Vec::new(),
+        time: Duration::from_millis(500),
+        output: "target/bench.json".into(),
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            // Passed by `cargo bench`
+            "--bench" => {}
+            "--time" => {
+                let ms = args.next().and_then(|ms| ms.parse().ok());
+                options.time = Duration::from_millis(ms.ok_or("Invalid value for --time")?);
+            }
+            "--year" => {
+                let year = args.next().and_then(|year| year.parse().ok());
+                options.year = Some(year.ok_or("Invalid value for --year")?);
+            }
+            "--output" => options.output = args.next().ok_or("Missing value for --output")?,
+            day => options
+                .days
+                .push(day.parse().map_err(|_| format!("Invalid day: {day}"))?),
+        }
+    }
+
+    Ok(options)
+}
+
+fn run() -> std::result::Result<(), String> {
+    let options = parse_options(env::args().skip(1))?;
+    let measurements = bench_puzzles(&options).map_err(|err| err.to_string())?;
+
+    println!(
+        "{:>4}  {:>3}  {:<5}  {:>10}  {:>12}  {:>12}",
+        "year", "day", "stage", "iterations", "min", "median"
+    );
+    for m in &measurements {
+        println!(
+            "{:>4}  {:>3}  {:<5}  {:>10}  {:>12?}  {:>12?}",
+            m.year,
+            m.day,
+            m.stage,
+            m.samples.len(),
+            m.min(),
+            m.median()
+        );
+    }
+
+    let output = Path::new(&options.output);
+    if let Some(dir) = output.parent() {
+        fs::create_dir_all(dir).map_err(|err| format!("Failed to create {dir:?}: {err}"))?;
+    }
+    fs::write(output, to_json(&measurements))
+        .map_err(|err| format!("Failed to write {output:?}: {err}"))?;
+    println!("Wrote {}", output.display());
+
+    Ok(())
+}
+
+fn main() {
+    if let Err(message) = run() {
+        eprintln!("{message}");
+        process::exit(1);
+    }
+}
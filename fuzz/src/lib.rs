@@ -0,0 +1,9 @@
+use advent_of_code::solver;
+
+/// Parses the input and solves every part of the day, which may fail, but must not panic.
+pub fn solve(year: u16, day: u8, data: &[u8]) {
+    if let Ok(input) = std::str::from_utf8(data) {
+        let puzzle = solver::puzzle(year, day).expect("there is a solution for the day");
+        let _ = puzzle.solve_all(input);
+    }
+}
@@ -0,0 +1,142 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Heap usage of a stretch of code, as counted by [`CountingAllocator`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct AllocationStats {
+    /// The number of allocations, including reallocations.
+    pub allocations: usize,
+    /// The total size of all allocations.
+    pub bytes: usize,
+    /// The most bytes in use at once, on top of those already in use at the start.
+    pub peak_bytes: usize,
+}
+
+impl fmt::Display for AllocationStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} bytes, peak {} bytes",
+            self.allocations, self.bytes, self.peak_bytes
+        )
+    }
+}
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+static CURRENT_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+fn record_allocation(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    BYTES.fetch_add(size, Ordering::Relaxed);
+    record_growth(size);
+}
+
+fn record_growth(size: usize) {
+    let current = CURRENT_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK_BYTES.fetch_max(current, Ordering::Relaxed);
+}
+
+fn record_shrinkage(size: usize) {
+    CURRENT_BYTES.fetch_sub(size, Ordering::Relaxed);
+}
+
+/// The system allocator, counting allocations and the bytes in use.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_shrinkage(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            BYTES.fetch_add(new_size, Ordering::Relaxed);
+            if new_size >= layout.size() {
+                record_growth(new_size - layout.size());
+            } else {
+                record_shrinkage(layout.size() - new_size);
+            }
+        }
+        new_ptr
+    }
+}
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Runs `f`, returning its heap usage if the `count-allocations` feature is enabled.
+///
+/// The counts are global, so they include allocations made by other threads in the meantime.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocationStats>) {
+    if !cfg!(feature = "count-allocations") {
+        return (f(), None);
+    }
+
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    let current_bytes = CURRENT_BYTES.load(Ordering::Relaxed);
+    PEAK_BYTES.store(current_bytes, Ordering::Relaxed);
+
+    let result = f();
+
+    let stats = AllocationStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: BYTES.load(Ordering::Relaxed) - bytes,
+        peak_bytes: PEAK_BYTES
+            .load(Ordering::Relaxed)
+            .saturating_sub(current_bytes),
+    };
+
+    (result, Some(stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "count-allocations")]
+    #[test]
+    fn measure_counts_allocations() {
+        let (_values, stats) = measure(|| {
+            let mut values: Vec<u64> = Vec::with_capacity(1000);
+            values.extend(0..2000);
+            (values, Box::new(0u32))
+        });
+        let stats = stats.unwrap();
+
+        assert!(stats.allocations >= 3);
+        assert!(stats.bytes >= 8 * 1000 + 8 * 2000 + 4);
+        assert!(stats.peak_bytes >= 8 * 2000);
+    }
+
+    #[cfg(not(feature = "count-allocations"))]
+    #[test]
+    fn measure_is_disabled_without_feature() {
+        assert_eq!(measure(|| 42), (42, None));
+    }
+}
@@ -0,0 +1,234 @@
+use alloc::vec::Vec;
+use core::{hash::Hash, mem};
+
+use crate::{
+    collections::{HashMap, HashSet},
+    observer::{notify, Observer},
+    Error, Result,
+};
+
+/// An unbounded two-state automaton that only stores its active cells.
+///
+/// Inactive cells without active neighbors always stay inactive, since they are never visited.
+#[derive(Debug, Clone)]
+pub struct Sparse<C> {
+    active: HashSet<C>,
+}
+
+impl<C: Copy + Eq + Hash> Sparse<C> {
+    pub fn new(active: impl IntoIterator<Item = C>) -> Self {
+        Sparse {
+            active: active.into_iter().collect(),
+        }
+    }
+
+    pub fn active(&self) -> &HashSet<C> {
+        &self.active
+    }
+
+    pub fn active_count(&self) -> usize {
+        self.active.len()
+    }
+
+    /// Advances one generation, where `rule` decides from whether a cell is active and how many
+    /// of its `neighbors` are whether it is active in the next generation.
+    pub fn step<I>(&mut self, neighbors: impl Fn(C) -> I, rule: impl Fn(bool, usize) -> bool)
+    where
+        I: IntoIterator<Item = C>,
+    {
+        let mut active_neighbor_counts: HashMap<C, usize> = HashMap::default();
+
+        for cell in self.active.iter().copied() {
+            active_neighbor_counts.entry(cell).or_insert(0);
+            for neighbor in neighbors(cell) {
+                *active_neighbor_counts.entry(neighbor).or_insert(0) += 1;
+            }
+        }
+
+        self.active = active_neighbor_counts
+            .into_iter()
+            .filter(|(cell, count)| rule(self.active.contains(cell), *count))
+            .map(|(cell, _)| cell)
+            .collect();
+    }
+}
+
+/// A bounded automaton over a fixed set of cells, with each cell's neighbors among them computed
+/// up front.
+///
+/// The cells are kept in the order they were given in, along with the two generations before
+/// the current one, so that stepping swaps buffers instead of allocating.
+#[derive(Debug, Clone)]
+pub struct Dense<C, T> {
+    positions: Vec<C>,
+    neighbors: Vec<Vec<usize>>,
+    cells: Vec<T>,
+    previous: Vec<T>,
+    before_previous: Vec<T>,
+}
+
+impl<C: Copy + Eq + Hash, T: Clone + PartialEq> Dense<C, T> {
+    /// Creates an automaton over `cells`, where the `neighbors` of a cell that are not among them
+    /// are left out.
+    pub fn new<I>(cells: impl IntoIterator<Item = (C, T)>, neighbors: impl Fn(C) -> I) -> Self
+    where
+        I: IntoIterator<Item = C>,
+    {
+        let (positions, cells): (Vec<C>, Vec<T>) = cells.into_iter().unzip();
+        let indices: HashMap<C, usize> = positions.iter().copied().zip(0..).collect();
+        let neighbors = positions
+            .iter()
+            .map(|pos| {
+                neighbors(*pos)
+                    .into_iter()
+                    .filter_map(|neighbor| indices.get(&neighbor).copied())
+                    .collect()
+            })
+            .collect();
+
+        Dense {
+            positions,
+            neighbors,
+            previous: cells.clone(),
+            before_previous: cells.clone(),
+            cells,
+        }
+    }
+
+    /// The cells along with their positions, in the order they were given in.
+    pub fn iter(&self) -> impl Iterator<Item = (C, &T)> {
+        self.positions.iter().copied().zip(self.cells.iter())
+    }
+
+    /// The cells in the order they were given in.
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Advances one generation, where `rule` maps each cell and the number of its neighbors that
+    /// are `active` to its next state. Returns whether any cell changed.
+    pub fn step(&mut self, active: impl Fn(&T) -> bool, rule: impl Fn(&T, usize) -> T) -> bool {
+        for (index, next) in self.before_previous.iter_mut().enumerate() {
+            let active_neighbor_count = self.neighbors[index]
+                .iter()
+                .filter(|neighbor| active(&self.cells[**neighbor]))
+                .count();
+
+            *next = rule(&self.cells[index], active_neighbor_count);
+        }
+
+        // The oldest generation is overwritten by the next one, which becomes the current one
+        mem::swap(&mut self.cells, &mut self.before_previous);
+        mem::swap(&mut self.previous, &mut self.before_previous);
+        self.cells != self.previous
+    }
+
+    /// Steps until no cell changes, passing the automaton to `observer` after each step that
+    /// changed it.
+    ///
+    /// Fails with [`Error::NoSolution`] if the cells return to where they were two steps before,
+    /// since they then alternate forever, or if they still change on the last of `max_steps`
+    /// steps, which bounds the longer cycles that are not detected.
+    pub fn run_until_stable(
+        &mut self,
+        max_steps: usize,
+        active: impl Fn(&T) -> bool,
+        rule: impl Fn(&T, usize) -> T,
+        observer: &mut impl Observer<Self>,
+    ) -> Result<()> {
+        for _ in 0..max_steps {
+            if !self.step(&active, &rule) {
+                return Ok(());
+            }
+            if self.cells == self.before_previous {
+                return Err(Error::NoSolution);
+            }
+            notify(observer, self)?;
+        }
+
+        Err(Error::NoSolution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        grid::{Grid, Position, Wrap, ADJACENT},
+        observer::Trace,
+    };
+
+    fn life(active: bool, count: usize) -> bool {
+        count == 3 || active && count == 2
+    }
+
+    #[test]
+    fn sparse_blinker_oscillates() {
+        let neighbors =
+            |(x, y): (i32, i32)| ADJACENT.map(|(dx, dy)| (x + dx as i32, y + dy as i32));
+        let horizontal = Sparse::new([(0, 1), (1, 1), (2, 1)]);
+        let vertical = Sparse::new([(1, 0), (1, 1), (1, 2)]);
+
+        let mut automaton = horizontal.clone();
+        automaton.step(neighbors, life);
+        assert_eq!(automaton.active(), vertical.active());
+        automaton.step(neighbors, life);
+        assert_eq!(automaton.active(), horizontal.active());
+    }
+
+    fn grid_automaton(grid: &Grid<bool>) -> Dense<Position, bool> {
+        Dense::new(grid.iter().map(|(pos, cell)| (pos, *cell)), |pos| {
+            grid.neighbors(pos, &ADJACENT, Wrap::None)
+                .map(|(pos, _)| pos)
+                .collect::<Vec<_>>()
+        })
+    }
+
+    #[test]
+    fn dense_block_is_stable() {
+        let grid = Grid::parse("....\n.##.\n.##.\n....", "`.` or `#`", |c| Some(c == '#')).unwrap();
+        let mut automaton = grid_automaton(&grid);
+
+        assert!(!automaton.step(|active| *active, |active, count| life(*active, count)));
+        assert!(automaton.values().eq(grid.values()));
+    }
+
+    #[test]
+    fn dense_blinker_is_not_stable() {
+        let grid = Grid::parse(".....\n..#..\n..#..\n..#..\n.....", "`.` or `#`", |c| {
+            Some(c == '#')
+        })
+        .unwrap();
+        let mut automaton = grid_automaton(&grid);
+
+        let mut trace = Trace::new();
+        assert_eq!(
+            automaton.run_until_stable(
+                100,
+                |active| *active,
+                |active, count| life(*active, count),
+                &mut trace
+            ),
+            Err(Error::NoSolution)
+        );
+        assert_eq!(trace.states.len(), 1);
+    }
+
+    #[test]
+    fn dense_longer_cycles_stop_at_the_limit() {
+        // A single active cell that moves around a ring of three, which is a cycle of three steps
+        let ring = [-1, 0, 1];
+        let mut automaton = Dense::new(ring.map(|x| (x, x == 0)), |x| [(x + 2) % 3 - 1]);
+
+        let mut trace = Trace::new();
+        assert_eq!(
+            automaton.run_until_stable(10, |active| *active, |_, count| count == 1, &mut trace),
+            Err(Error::NoSolution)
+        );
+        assert_eq!(trace.states.len(), 10);
+        assert!(trace.states[2]
+            .iter()
+            .eq([(-1, &false), (0, &true), (1, &false)]));
+        assert!(trace.states[9].iter().eq(trace.states[0].iter()));
+    }
+}
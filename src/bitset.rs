@@ -0,0 +1,251 @@
+//! Sets of small numbers stored one bit per number, which grow to fit the largest.
+
+use alloc::{vec, vec::Vec};
+use core::{fmt, iter};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A set of numbers, where the number `n` is bit `n % 64` of word `n / 64`.
+///
+/// Trailing zero words do not matter, so sets with the same numbers are equal whatever their
+/// capacity.
+#[derive(Clone, Default)]
+pub struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    pub fn new() -> Self {
+        BitSet::default()
+    }
+
+    /// An empty set with room for the numbers below `bits` without growing.
+    pub fn with_capacity(bits: usize) -> Self {
+        BitSet {
+            words: vec![0; (bits + WORD_BITS - 1) / WORD_BITS],
+        }
+    }
+
+    pub fn from_words(words: Vec<u64>) -> Self {
+        BitSet { words }
+    }
+
+    pub fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    pub fn contains(&self, value: usize) -> bool {
+        self.words
+            .get(value / WORD_BITS)
+            .map_or(false, |word| word & 1 << (value % WORD_BITS) != 0)
+    }
+
+    /// Adds `value`, returning whether it was missing.
+    pub fn insert(&mut self, value: usize) -> bool {
+        let index = value / WORD_BITS;
+        if index >= self.words.len() {
+            self.words.resize(index + 1, 0);
+        }
+
+        let bit = 1 << (value % WORD_BITS);
+        let missing = self.words[index] & bit == 0;
+        self.words[index] |= bit;
+        missing
+    }
+
+    /// Removes `value`, returning whether it was present.
+    pub fn remove(&mut self, value: usize) -> bool {
+        let present = self.contains(value);
+        if present {
+            self.words[value / WORD_BITS] &= !(1 << (value % WORD_BITS));
+        }
+        present
+    }
+
+    /// The number of numbers in the set.
+    pub fn len(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|word| *word == 0)
+    }
+
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// The numbers in the set, in increasing order.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            words: self.words.iter(),
+            offset: 0,
+            word: 0,
+        }
+    }
+
+    /// Applies `f` to each pair of words, treating missing words as zero.
+    fn combine_with(&mut self, other: &BitSet, f: impl Fn(u64, u64) -> u64) {
+        if other.words.len() > self.words.len() {
+            self.words.resize(other.words.len(), 0);
+        }
+        let others = other.words.iter().copied().chain(iter::repeat(0));
+        for (word, other) in self.words.iter_mut().zip(others) {
+            *word = f(*word, other);
+        }
+    }
+
+    pub fn union_with(&mut self, other: &BitSet) {
+        self.combine_with(other, |a, b| a | b);
+    }
+
+    pub fn intersect_with(&mut self, other: &BitSet) {
+        self.combine_with(other, |a, b| a & b);
+    }
+
+    pub fn difference_with(&mut self, other: &BitSet) {
+        self.combine_with(other, |a, b| a & !b);
+    }
+
+    pub fn symmetric_difference_with(&mut self, other: &BitSet) {
+        self.combine_with(other, |a, b| a ^ b);
+    }
+
+    /// Pairs up the words of both sets, padding the shorter one with zeros.
+    fn word_pairs<'a>(&'a self, other: &'a BitSet) -> impl Iterator<Item = (u64, u64)> + 'a {
+        let len = self.words.len().max(other.words.len());
+        let words = |set: &'a BitSet| set.words.iter().copied().chain(iter::repeat(0));
+        words(self).zip(words(other)).take(len)
+    }
+
+    pub fn is_subset(&self, other: &BitSet) -> bool {
+        self.word_pairs(other).all(|(a, b)| a & !b == 0)
+    }
+
+    pub fn is_disjoint(&self, other: &BitSet) -> bool {
+        self.word_pairs(other).all(|(a, b)| a & b == 0)
+    }
+}
+
+impl PartialEq for BitSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.word_pairs(other).all(|(a, b)| a == b)
+    }
+}
+
+impl Eq for BitSet {}
+
+impl fmt::Debug for BitSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<usize> for BitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(values: I) -> Self {
+        let mut set = BitSet::new();
+        set.extend(values);
+        set
+    }
+}
+
+impl Extend<usize> for BitSet {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, values: I) {
+        for value in values {
+            self.insert(value);
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a BitSet {
+    type Item = usize;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// The iterator returned by [`BitSet::iter`].
+pub struct Iter<'a> {
+    words: core::slice::Iter<'a, u64>,
+    // The number of the lowest bit of `word`
+    offset: usize,
+    // What is left of the current word
+    word: u64,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.word == 0 {
+            self.word = *self.words.next()?;
+            self.offset += WORD_BITS;
+        }
+
+        let bit = self.word.trailing_zeros() as usize;
+        // Clears the lowest set bit
+        self.word &= self.word - 1;
+        Some(self.offset - WORD_BITS + bit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sets_grow_to_fit_their_numbers() {
+        let mut set = BitSet::new();
+        assert!(set.insert(3));
+        assert!(set.insert(200));
+        assert!(!set.insert(3));
+        assert_eq!(set.words().len(), 4);
+
+        assert!(set.contains(200));
+        assert!(!set.contains(64) && !set.contains(1000));
+        assert_eq!(set.iter().collect::<Vec<_>>(), [3, 200]);
+        assert_eq!(set.len(), 2);
+
+        assert!(set.remove(200));
+        assert!(!set.remove(200));
+        assert_eq!(set, BitSet::from_iter([3]));
+        assert_eq!(set, BitSet::from_words(vec![1 << 3]));
+        assert_ne!(set, BitSet::with_capacity(100));
+
+        set.clear();
+        assert!(set.is_empty());
+        assert_eq!(format!("{set:?}"), "{}");
+    }
+
+    #[test]
+    fn set_algebra_pads_with_zeros() {
+        let small: BitSet = [1, 2, 3].into_iter().collect();
+        let large: BitSet = [2, 3, 70, 130].into_iter().collect();
+
+        let mut set = small.clone();
+        set.union_with(&large);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [1, 2, 3, 70, 130]);
+
+        let mut set = large.clone();
+        set.intersect_with(&small);
+        assert_eq!(format!("{set:?}"), "{2, 3}");
+
+        let mut set = large.clone();
+        set.difference_with(&small);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [70, 130]);
+
+        let mut set = small.clone();
+        set.symmetric_difference_with(&large);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [1, 70, 130]);
+
+        assert!(BitSet::from_iter([2, 70]).is_subset(&large));
+        assert!(!small.is_subset(&large));
+        assert!(BitSet::from_iter([1, 64, 200]).is_disjoint(&large));
+        assert!(!small.is_disjoint(&large));
+    }
+}
@@ -0,0 +1,16 @@
+//! Hash maps and sets that are available without `std`.
+//!
+//! They hash with `FxHasher`, which is fast for the small keys of the puzzles, but not resistant
+//! to collisions chosen by an adversary.
+
+use core::hash::BuildHasherDefault;
+
+use rustc_hash::FxHasher;
+
+pub use hashbrown::{hash_map, hash_set};
+
+pub type BuildHasher = BuildHasherDefault<FxHasher>;
+
+pub type HashMap<K, V> = hashbrown::HashMap<K, V, BuildHasher>;
+
+pub type HashSet<T> = hashbrown::HashSet<T, BuildHasher>;
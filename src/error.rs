@@ -0,0 +1,129 @@
+use alloc::{format, string::String};
+use core::{fmt, str::FromStr};
+
+pub type Result<T, E = Error> = core::result::Result<T, E>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The input is malformed. Lines and columns are 1-based.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input is well-formed, but the puzzle has no answer for it.
+    NoSolution,
+    /// An observer stopped the simulation before it finished.
+    Stopped,
+    /// The input could not be read.
+    Io(String),
+}
+
+impl Error {
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Error::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// Creates a parse error at the start of `fragment`, which must be a slice of `input`.
+    pub fn at(input: &str, fragment: &str, message: impl Into<String>) -> Self {
+        let offset = (fragment.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| *offset <= input.len())
+            .expect("fragment is not a slice of input");
+
+        Error::at_offset(input, offset, message)
+    }
+
+    /// Creates a parse error at the given byte offset into `input`.
+    pub fn at_offset(input: &str, offset: usize, message: impl Into<String>) -> Self {
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+
+        let line = 1 + before.matches('\n').count();
+        let column = 1 + before[line_start..].chars().count();
+
+        Error::parse(line, column, message)
+    }
+
+    /// Creates a parse error right after the end of `input`.
+    pub fn at_end(input: &str, message: impl Into<String>) -> Self {
+        Error::at_offset(input, input.len(), message)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "Parse error at line {line}, column {column}: {message}"),
+            Error::NoSolution => write!(f, "No solution found"),
+            Error::Stopped => write!(f, "Stopped by the observer"),
+            Error::Io(message) => write!(f, "Failed to read input: {message}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::Io(err.to_string())
+    }
+}
+
+/// Parses `fragment`, a slice of `input`, reporting failures at its position.
+pub fn parse_at<T>(input: &str, fragment: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    fragment.parse().map_err(|err| {
+        Error::at(
+            input,
+            fragment,
+            format!("Invalid value {fragment:?}: {err}"),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn at_reports_line_and_column() {
+        let input = "abc\ndéf\nghi";
+        let fragment = &input[input.find('f').unwrap()..];
+
+        assert_eq!(
+            Error::at(input, fragment, "oops"),
+            Error::parse(2, 3, "oops")
+        );
+        assert_eq!(Error::at(input, input, "oops"), Error::parse(1, 1, "oops"));
+        assert_eq!(Error::at_end(input, "oops"), Error::parse(3, 4, "oops"));
+    }
+
+    #[test]
+    fn parse_at_reports_invalid_values() {
+        let input = "12\n3x";
+
+        assert_eq!(parse_at::<u32>(input, &input[..2]), Ok(12));
+        assert!(matches!(
+            parse_at::<u32>(input, &input[3..]),
+            Err(Error::Parse {
+                line: 2,
+                column: 1,
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,369 @@
+//! Directed graphs over nodes numbered from zero, with the searches the puzzles need.
+
+use alloc::{collections::VecDeque, vec, vec::Vec};
+use core::ops::Range;
+
+/// Nodes that lead back to the first of them, in the order of the edges between them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle(pub Vec<usize>);
+
+/// A directed graph that stores the outgoing edges of each node, with a weight on every edge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graph<W = ()> {
+    edges: Vec<Vec<(usize, W)>>,
+}
+
+impl<W> Graph<W> {
+    pub fn new(node_count: usize) -> Self {
+        Graph {
+            edges: (0..node_count).map(|_| Vec::new()).collect(),
+        }
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Adds a node without edges, returning it.
+    pub fn add_node(&mut self) -> usize {
+        self.edges.push(Vec::new());
+        self.edges.len() - 1
+    }
+
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: W) {
+        assert!(to < self.node_count(), "Edge to a missing node");
+        self.edges[from].push((to, weight));
+    }
+
+    /// The nodes that `node` has edges to, with the weights of the edges, in the order they were
+    /// added.
+    pub fn successors(&self, node: usize) -> impl Iterator<Item = (usize, &W)> {
+        self.edges[node].iter().map(|(to, weight)| (*to, weight))
+    }
+
+    /// The same graph with every edge pointing the other way.
+    pub fn reversed(&self) -> Self
+    where
+        W: Clone,
+    {
+        let mut reversed = Graph::new(self.node_count());
+        for (from, edges) in self.edges.iter().enumerate() {
+            for (to, weight) in edges {
+                reversed.add_edge(*to, from, weight.clone());
+            }
+        }
+        reversed
+    }
+
+    /// Visits the nodes reachable from `starts` breadth first, along with their distance from the
+    /// nearest start.
+    pub fn bfs(&self, starts: impl IntoIterator<Item = usize>) -> Bfs<'_, W> {
+        let mut visited = vec![false; self.node_count()];
+        let mut queue = VecDeque::new();
+        for start in starts {
+            if !visited[start] {
+                visited[start] = true;
+                queue.push_back((start, 0));
+            }
+        }
+
+        Bfs {
+            graph: self,
+            visited,
+            queue,
+        }
+    }
+
+    /// Visits the nodes reachable from `starts` depth first, each before its successors.
+    pub fn dfs(&self, starts: impl IntoIterator<Item = usize>) -> Dfs<'_, W> {
+        let mut stack: Vec<usize> = starts.into_iter().collect();
+        stack.reverse();
+
+        Dfs {
+            graph: self,
+            visited: vec![false; self.node_count()],
+            stack,
+        }
+    }
+
+    /// Orders every node before its successors, or finds a cycle if there is no such order.
+    pub fn topological_order(&self) -> Result<Vec<usize>, Cycle> {
+        self.topological_order_from(0..self.node_count())
+    }
+
+    /// Like [`Graph::topological_order`], but only orders the nodes reachable from `starts`, so
+    /// cycles elsewhere do not matter.
+    pub fn topological_order_from(
+        &self,
+        starts: impl IntoIterator<Item = usize>,
+    ) -> Result<Vec<usize>, Cycle> {
+        #[derive(Copy, Clone, PartialEq, Eq)]
+        enum State {
+            Unvisited,
+            // On the path from the start that is being searched
+            OnPath,
+            Done,
+        }
+
+        let mut states = vec![State::Unvisited; self.node_count()];
+        let mut order = Vec::new();
+
+        for start in starts {
+            if states[start] != State::Unvisited {
+                continue;
+            }
+
+            // The path from `start`, with the index of the next edge to follow from each node
+            let mut path = vec![(start, 0)];
+            states[start] = State::OnPath;
+
+            while let Some((node, edge)) = path.last_mut() {
+                let node = *node;
+                match self.edges[node].get(*edge) {
+                    Some((next, _)) => {
+                        *edge += 1;
+                        match states[*next] {
+                            State::Unvisited => {
+                                states[*next] = State::OnPath;
+                                path.push((*next, 0));
+                            }
+                            State::OnPath => {
+                                let cycle_start = path.iter().position(|(n, _)| n == next).unwrap();
+                                return Err(Cycle(
+                                    path[cycle_start..].iter().map(|(n, _)| *n).collect(),
+                                ));
+                            }
+                            State::Done => {}
+                        }
+                    }
+                    None => {
+                        states[node] = State::Done;
+                        order.push(node);
+                        path.pop();
+                    }
+                }
+            }
+        }
+
+        order.reverse();
+        Ok(order)
+    }
+
+    /// Counts the paths from `start` to every node, where each path counts as many times as the
+    /// product of the `weight`s of its edges. The count of `start` includes the empty path, and
+    /// the count of a node is `None` if it does not fit in a `u64`.
+    ///
+    /// Fails if there is a cycle reachable from `start`, which makes the counts infinite.
+    pub fn path_counts(
+        &self,
+        start: usize,
+        weight: impl Fn(&W) -> u64,
+    ) -> Result<Vec<Option<u64>>, Cycle> {
+        let order = self.topological_order_from([start])?;
+
+        let mut counts: Vec<Option<u64>> = vec![Some(0); self.node_count()];
+        counts[start] = Some(1);
+
+        // Every path to a node is counted before the node passes its count on
+        for node in order {
+            let count = counts[node];
+            for (next, edge_weight) in self.successors(node) {
+                counts[next] = counts[next].zip(count).and_then(|(next_count, count)| {
+                    next_count.checked_add(count.checked_mul(weight(edge_weight))?)
+                });
+            }
+        }
+
+        Ok(counts)
+    }
+
+    /// Gives each of `nodes` a distinct successor, treating the graph as bipartite, with `nodes`
+    /// on one side and their successors on the other. Returns the successor of each of `nodes`, or
+    /// `None` if some node cannot have one.
+    ///
+    /// The assignment grows one node at a time along augmenting paths, which takes the product of
+    /// the numbers of nodes and edges at worst.
+    pub fn assignment(&self, nodes: Range<usize>) -> Option<Vec<usize>> {
+        fn assign<W>(
+            graph: &Graph<W>,
+            node: usize,
+            visited: &mut [bool],
+            assigned_nodes: &mut [Option<usize>],
+        ) -> bool {
+            graph.edges[node].iter().any(|(successor, _)| {
+                if visited[*successor] {
+                    return false;
+                }
+                visited[*successor] = true;
+
+                let reassigned = match assigned_nodes[*successor] {
+                    Some(other_node) => assign(graph, other_node, visited, assigned_nodes),
+                    None => true,
+                };
+                if reassigned {
+                    assigned_nodes[*successor] = Some(node);
+                }
+                reassigned
+            })
+        }
+
+        // The node that each successor is assigned to
+        let mut assigned_nodes = vec![None; self.node_count()];
+        for node in nodes.clone() {
+            let mut visited = vec![false; self.node_count()];
+            if !assign(self, node, &mut visited, &mut assigned_nodes) {
+                return None;
+            }
+        }
+
+        let mut successors = vec![0; nodes.len()];
+        for (successor, node) in assigned_nodes.into_iter().enumerate() {
+            if let Some(node) = node {
+                successors[node - nodes.start] = successor;
+            }
+        }
+        Some(successors)
+    }
+}
+
+/// The iterator returned by [`Graph::bfs`].
+pub struct Bfs<'a, W> {
+    graph: &'a Graph<W>,
+    visited: Vec<bool>,
+    queue: VecDeque<(usize, usize)>,
+}
+
+impl<W> Iterator for Bfs<'_, W> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (node, distance) = self.queue.pop_front()?;
+
+        for (next, _) in self.graph.successors(node) {
+            if !self.visited[next] {
+                self.visited[next] = true;
+                self.queue.push_back((next, distance + 1));
+            }
+        }
+
+        Some((node, distance))
+    }
+}
+
+/// The iterator returned by [`Graph::dfs`].
+pub struct Dfs<'a, W> {
+    graph: &'a Graph<W>,
+    visited: Vec<bool>,
+    stack: Vec<usize>,
+}
+
+impl<W> Iterator for Dfs<'_, W> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let node = self.stack.pop()?;
+            if self.visited[node] {
+                continue;
+            }
+            self.visited[node] = true;
+
+            // Pushed in reverse, so the first successor is visited first
+            let successors = self.graph.edges[node].iter().rev();
+            self.stack.extend(
+                successors
+                    .map(|(next, _)| *next)
+                    .filter(|next| !self.visited[*next]),
+            );
+
+            return Some(node);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0 → 1 → 3, 0 → 2 → 3 → 4, with the edge weights as given.
+    fn diamond(weights: [u64; 5]) -> Graph<u64> {
+        let mut graph = Graph::new(5);
+        for ((from, to), weight) in [(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)]
+            .into_iter()
+            .zip(weights)
+        {
+            graph.add_edge(from, to, weight);
+        }
+        graph
+    }
+
+    #[test]
+    fn searches_visit_reachable_nodes() {
+        let graph = diamond([1; 5]);
+
+        assert_eq!(
+            graph.bfs([0]).collect::<Vec<_>>(),
+            [(0, 0), (1, 1), (2, 1), (3, 2), (4, 3)]
+        );
+        assert_eq!(
+            graph.bfs([2, 1]).collect::<Vec<_>>(),
+            [(2, 0), (1, 0), (3, 1), (4, 2)]
+        );
+        assert_eq!(graph.dfs([0]).collect::<Vec<_>>(), [0, 1, 3, 4, 2]);
+        assert_eq!(graph.reversed().dfs([3]).collect::<Vec<_>>(), [3, 1, 0, 2]);
+    }
+
+    #[test]
+    fn topological_order_reports_cycles() {
+        let mut graph = diamond([1; 5]);
+        assert_eq!(graph.topological_order(), Ok(vec![0, 2, 1, 3, 4]));
+
+        graph.add_edge(4, 1, 1);
+        assert_eq!(graph.topological_order(), Err(Cycle(vec![1, 3, 4])));
+        assert_eq!(graph.topological_order_from([4]), Err(Cycle(vec![4, 1, 3])));
+
+        let node = graph.add_node();
+        graph.add_edge(node, 0, 1);
+        assert_eq!(
+            graph.reversed().topological_order_from([node]),
+            Ok(vec![node])
+        );
+    }
+
+    #[test]
+    fn path_counts_multiply_weights() {
+        let graph = diamond([2, 3, 5, 7, 1]);
+
+        assert_eq!(
+            graph.path_counts(0, |_| 1),
+            Ok(vec![Some(1), Some(1), Some(1), Some(2), Some(2)])
+        );
+        assert_eq!(
+            graph.path_counts(0, |weight| *weight),
+            Ok(vec![Some(1), Some(2), Some(3), Some(31), Some(31)])
+        );
+        assert_eq!(
+            graph.path_counts(3, |weight| *weight),
+            Ok(vec![Some(0), Some(0), Some(0), Some(1), Some(1)])
+        );
+
+        let counts = graph.path_counts(0, |weight| if *weight == 5 { u64::MAX } else { 1 });
+        assert_eq!(counts.unwrap()[1..], [Some(1), Some(1), None, None]);
+    }
+
+    #[test]
+    fn assignment_reassigns_along_augmenting_paths() {
+        // Nodes 0 to 2 on one side, and 3 to 5 on the other
+        let mut graph = Graph::new(6);
+        for (node, successor) in [(0, 3), (0, 4), (1, 3), (2, 4), (2, 5)] {
+            graph.add_edge(node, successor, ());
+        }
+        assert_eq!(graph.assignment(0..3), Some(vec![4, 3, 5]));
+        assert_eq!(graph.assignment(1..3), Some(vec![3, 4]));
+
+        let mut graph = Graph::new(3);
+        graph.add_edge(0, 2, ());
+        graph.add_edge(1, 2, ());
+        assert_eq!(graph.assignment(0..2), None);
+    }
+}
@@ -0,0 +1,491 @@
+use alloc::{format, vec::Vec};
+use core::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{Error, Result};
+
+/// A cell position as `(x, y)`, with `y` growing downwards.
+pub type Position = (usize, usize);
+
+/// A step between positions as `(dx, dy)`.
+pub type Offset = (isize, isize);
+
+pub const ORTHOGONAL: [Offset; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+pub const ADJACENT: [Offset; 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Which edges of a grid wrap around to the opposite edge.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Wrap {
+    None,
+    Horizontal,
+    Vertical,
+    Both,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from cells in row-major order.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "Grid size mismatch");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Position) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+        Grid::new(width, height, cells)
+    }
+
+    /// Parses one row per line of `input`, reporting characters for which `f` returns `None` as
+    /// not being `expected`.
+    pub fn parse(input: &str, expected: &str, f: impl FnMut(char) -> Option<T>) -> Result<Self> {
+        Grid::parse_at(input, input, expected, f)
+    }
+
+    /// Like [`Grid::parse`], but parses `fragment`, a slice of `input`.
+    pub fn parse_at(
+        input: &str,
+        fragment: &str,
+        expected: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self> {
+        let width = fragment
+            .lines()
+            .next()
+            .map_or(0, |line| line.chars().count());
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for line in fragment.lines() {
+            if line.chars().count() != width {
+                return Err(Error::at(
+                    input,
+                    line,
+                    format!("Expected a row of {width} cells"),
+                ));
+            }
+
+            for (index, c) in line.char_indices() {
+                let cell = f(c).ok_or_else(|| {
+                    Error::at(input, &line[index..], format!("Expected {expected}"))
+                })?;
+                cells.push(cell);
+            }
+
+            height += 1;
+        }
+
+        Ok(Grid::new(width, height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): Position) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (x, y): Position) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Steps from `pos` by `offset`, returning `None` when leaving the grid along a non-wrapping axis.
+    pub fn offset(&self, (x, y): Position, (dx, dy): Offset, wrap: Wrap) -> Option<Position> {
+        let wrap_x = matches!(wrap, Wrap::Horizontal | Wrap::Both);
+        let wrap_y = matches!(wrap, Wrap::Vertical | Wrap::Both);
+
+        Some((
+            step(x, dx, self.width, wrap_x)?,
+            step(y, dy, self.height, wrap_y)?,
+        ))
+    }
+
+    pub fn neighbors<'a>(
+        &'a self,
+        pos: Position,
+        offsets: &'a [Offset],
+        wrap: Wrap,
+    ) -> impl Iterator<Item = (Position, &'a T)> + 'a {
+        offsets
+            .iter()
+            .filter_map(move |offset| self.offset(pos, *offset, wrap))
+            .map(move |pos| (pos, &self[pos]))
+    }
+
+    /// Repeatedly steps from `pos` by `offset`, not including `pos` itself.
+    ///
+    /// Never ends if the ray wraps around along every axis it moves along.
+    pub fn ray(
+        &self,
+        mut pos: Position,
+        offset: Offset,
+        wrap: Wrap,
+    ) -> impl Iterator<Item = (Position, &T)> + '_ {
+        core::iter::from_fn(move || {
+            pos = self.offset(pos, offset, wrap)?;
+            Some((pos, &self[pos]))
+        })
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks_exact` panics on a chunk size of zero
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(Position, &T) -> U) -> Grid<U> {
+        Grid::new(
+            self.width,
+            self.height,
+            self.iter().map(|(pos, cell)| f(pos, cell)).collect(),
+        )
+    }
+
+    pub fn view(&self, transform: Transform) -> GridView<'_, T> {
+        GridView {
+            grid: self,
+            transform,
+        }
+    }
+
+    pub fn transformed(&self, transform: Transform) -> Self
+    where
+        T: Clone,
+    {
+        let view = self.view(transform);
+        Grid::from_fn(view.width(), view.height(), |pos| view[pos].clone())
+    }
+}
+
+fn step(value: usize, delta: isize, len: usize, wrap: bool) -> Option<usize> {
+    if wrap && len > 0 {
+        let len = len as isize;
+        Some((value as isize + delta % len).rem_euclid(len) as usize)
+    } else {
+        let value = value as isize + delta;
+        if 0 <= value && (value as usize) < len {
+            Some(value as usize)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &Self::Output {
+        self.get(pos).expect("Position out of bounds")
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut Self::Output {
+        self.get_mut(pos).expect("Position out of bounds")
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// A clockwise rotation by a multiple of 90 degrees.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Rotation {
+    None,
+    Right,
+    Half,
+    Left,
+}
+
+pub const ROTATIONS: [Rotation; 4] = [
+    Rotation::None,
+    Rotation::Right,
+    Rotation::Half,
+    Rotation::Left,
+];
+
+impl Rotation {
+    /// Returns the rotation by `degrees` clockwise, if it is a multiple of 90.
+    pub fn from_degrees(degrees: i32) -> Option<Self> {
+        if degrees % 90 == 0 {
+            Some(ROTATIONS[(degrees / 90).rem_euclid(4) as usize])
+        } else {
+            None
+        }
+    }
+
+    pub fn inverse(self) -> Self {
+        match self {
+            Rotation::Right => Rotation::Left,
+            Rotation::Left => Rotation::Right,
+            rotation => rotation,
+        }
+    }
+
+    pub fn rotate(self, (dx, dy): Offset) -> Offset {
+        match self {
+            Rotation::None => (dx, dy),
+            Rotation::Right => (-dy, dx),
+            Rotation::Half => (-dx, -dy),
+            Rotation::Left => (dy, -dx),
+        }
+    }
+}
+
+/// One of the 8 symmetries of a square: an optional transposition followed by a rotation.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Transform {
+    pub transposed: bool,
+    pub rotation: Rotation,
+}
+
+impl Transform {
+    pub const IDENTITY: Transform = Transform {
+        transposed: false,
+        rotation: Rotation::None,
+    };
+
+    fn swaps_axes(self) -> bool {
+        self.transposed != matches!(self.rotation, Rotation::Right | Rotation::Left)
+    }
+
+    /// Maps a position in the transformed grid to the position in a source grid of the given size.
+    pub fn source_position(self, (x, y): Position, width: usize, height: usize) -> Position {
+        let (width, height) = if self.transposed {
+            (height, width)
+        } else {
+            (width, height)
+        };
+
+        let (x, y) = match self.rotation {
+            Rotation::None => (x, y),
+            Rotation::Right => (y, height - 1 - x),
+            Rotation::Half => (width - 1 - x, height - 1 - y),
+            Rotation::Left => (width - 1 - y, x),
+        };
+
+        if self.transposed {
+            (y, x)
+        } else {
+            (x, y)
+        }
+    }
+}
+
+pub const TRANSFORMS: [Transform; 8] = {
+    let mut transforms = [Transform::IDENTITY; 8];
+    let mut index = 0;
+    while index < 8 {
+        transforms[index] = Transform {
+            transposed: index >= 4,
+            rotation: ROTATIONS[index % 4],
+        };
+        index += 1;
+    }
+    transforms
+};
+
+/// A borrowed grid seen through a [`Transform`].
+#[derive(Debug, Copy, Clone)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    transform: Transform,
+}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn transform(&self) -> Transform {
+        self.transform
+    }
+
+    pub fn width(&self) -> usize {
+        if self.transform.swaps_axes() {
+            self.grid.height
+        } else {
+            self.grid.width
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        if self.transform.swaps_axes() {
+            self.grid.width
+        } else {
+            self.grid.height
+        }
+    }
+
+    pub fn get(&self, (x, y): Position) -> Option<&'a T> {
+        if x < self.width() && y < self.height() {
+            let pos = self
+                .transform
+                .source_position((x, y), self.grid.width, self.grid.height);
+            self.grid.get(pos)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Index<Position> for GridView<'_, T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &Self::Output {
+        self.get(pos).expect("Position out of bounds")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef", "a letter", Some).unwrap()
+    }
+
+    #[test]
+    fn parse_reports_invalid_cells() {
+        assert_eq!(
+            Grid::parse(".#\n#x", "`.` or `#`", |c| match c {
+                '.' => Some(false),
+                '#' => Some(true),
+                _ => None,
+            }),
+            Err(Error::parse(2, 2, "Expected `.` or `#`"))
+        );
+        assert_eq!(
+            Grid::parse("ab\nc", "a letter", Some),
+            Err(Error::parse(2, 1, "Expected a row of 2 cells"))
+        );
+    }
+
+    #[test]
+    fn access_is_bounds_checked() {
+        let grid = grid();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get((2, 1)), Some(&'f'));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+    }
+
+    #[test]
+    fn offsets_wrap() {
+        let grid = grid();
+
+        assert_eq!(grid.offset((0, 0), (-1, 0), Wrap::None), None);
+        assert_eq!(grid.offset((0, 0), (-1, 0), Wrap::Horizontal), Some((2, 0)));
+        assert_eq!(grid.offset((0, 0), (-1, -1), Wrap::Horizontal), None);
+        assert_eq!(grid.offset((0, 0), (-1, -1), Wrap::Both), Some((2, 1)));
+        assert_eq!(grid.offset((2, 1), (7, 0), Wrap::Horizontal), Some((0, 1)));
+    }
+
+    #[test]
+    fn neighbors_and_rays() {
+        let grid = grid();
+
+        let neighbors: String = grid
+            .neighbors((0, 0), &ADJACENT, Wrap::None)
+            .map(|(_, c)| c)
+            .collect();
+        assert_eq!(neighbors, "bde");
+
+        let ray: String = grid
+            .ray((0, 0), (1, 0), Wrap::None)
+            .map(|(_, c)| c)
+            .collect();
+        assert_eq!(ray, "bc");
+    }
+
+    #[test]
+    fn transforms_are_dihedral() {
+        let grid = grid();
+        let transformed = |transposed, rotation| {
+            grid.transformed(Transform {
+                transposed,
+                rotation,
+            })
+            .to_string()
+        };
+
+        assert_eq!(transformed(false, Rotation::None), "abc\ndef\n");
+        assert_eq!(transformed(false, Rotation::Right), "da\neb\nfc\n");
+        assert_eq!(transformed(false, Rotation::Half), "fed\ncba\n");
+        assert_eq!(transformed(false, Rotation::Left), "cf\nbe\nad\n");
+        assert_eq!(transformed(true, Rotation::None), "ad\nbe\ncf\n");
+        assert_eq!(transformed(true, Rotation::Right), "cba\nfed\n");
+
+        let mut all: Vec<_> = TRANSFORMS
+            .iter()
+            .map(|transform| grid.transformed(*transform).to_string())
+            .collect();
+        all.sort();
+        all.dedup();
+        assert_eq!(all.len(), 8);
+    }
+
+    #[test]
+    fn rotations_turn_clockwise() {
+        assert_eq!(Rotation::Right.rotate((1, 0)), (0, 1));
+        assert_eq!(Rotation::Left.rotate((1, 0)), (0, -1));
+        assert_eq!(Rotation::from_degrees(-90), Some(Rotation::Left));
+        assert_eq!(Rotation::from_degrees(450), Some(Rotation::Right));
+        assert_eq!(Rotation::from_degrees(45), None);
+    }
+}
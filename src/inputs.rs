@@ -0,0 +1,272 @@
+#[cfg(feature = "fetch")]
+use std::fmt;
+use std::{
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    process,
+};
+
+use crate::{Error, Result};
+
+/// A source of puzzle inputs.
+pub trait Fetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String>;
+}
+
+/// Downloads inputs over HTTP, authenticated by a session cookie.
+#[cfg(feature = "fetch")]
+#[derive(Debug, Clone)]
+pub struct HttpFetcher {
+    base_url: String,
+    session: String,
+}
+
+#[cfg(feature = "fetch")]
+impl HttpFetcher {
+    /// Fails if the session has characters that a cookie cannot hold.
+    pub fn new(session: impl Into<String>) -> Result<Self> {
+        HttpFetcher::with_base_url("https://adventofcode.com", session)
+    }
+
+    /// Fetches from `<base_url>/<year>/day/<day>/input` instead of the actual site.
+    pub fn with_base_url(base_url: impl Into<String>, session: impl Into<String>) -> Result<Self> {
+        let session = session.into();
+        // The characters allowed in cookie values by RFC 6265
+        let is_cookie_char = |c: char| c.is_ascii_graphic() && !matches!(c, '"' | ',' | ';' | '\\');
+        if session.is_empty() || !session.chars().all(is_cookie_char) {
+            return Err(Error::Io(
+                "Invalid session, which must be a cookie value without spaces, quotes, commas, \
+                 semicolons or backslashes"
+                    .into(),
+            ));
+        }
+
+        Ok(HttpFetcher {
+            base_url: base_url.into(),
+            session,
+        })
+    }
+
+    pub fn url(&self, year: u16, day: u8) -> String {
+        format!(
+            "{}/{year}/day/{day}/input",
+            self.base_url.trim_end_matches('/')
+        )
+    }
+}
+
+/// Identifies the requests, as the site asks of automated tools.
+#[cfg(feature = "fetch")]
+const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    " (",
+    env!("CARGO_PKG_REPOSITORY"),
+    ")"
+);
+
+#[cfg(feature = "fetch")]
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String> {
+        let url = self.url(year, day);
+        let failed = |err: &dyn fmt::Display| Error::Io(format!("Failed to fetch {url}: {err}"));
+
+        ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .call()
+            .map_err(|err| failed(&err))?
+            .into_string()
+            .map_err(|err| failed(&err))
+    }
+}
+
+/// Inputs stored in a directory as `<dir>/<year>/dayNN.txt`, fetched on first use if a fetcher
+/// is given.
+pub struct InputCache {
+    dir: PathBuf,
+    fetcher: Option<Box<dyn Fetcher>>,
+}
+
+impl InputCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        InputCache {
+            dir: dir.into(),
+            fetcher: None,
+        }
+    }
+
+    pub fn with_fetcher(mut self, fetcher: impl Fetcher + 'static) -> Self {
+        self.fetcher = Some(Box::new(fetcher));
+        self
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day{day:02}.txt"))
+    }
+
+    /// Returns the cached input for the day, fetching and caching it if missing.
+    pub fn load(&self, year: u16, day: u8) -> Result<String> {
+        let path = self.path(year, day);
+
+        match fs::read_to_string(&path) {
+            Ok(input) => return Ok(input),
+            Err(err) if err.kind() != ErrorKind::NotFound => {
+                return Err(Error::Io(format!("{}: {err}", path.display())))
+            }
+            Err(_) => {}
+        }
+
+        let fetcher = self.fetcher.as_ref().ok_or_else(|| {
+            Error::Io(format!(
+                "{} is not cached, and there is no session to fetch it with",
+                path.display()
+            ))
+        })?;
+        let input = fetcher.fetch(year, day)?;
+
+        // Written to a temporary file that is then renamed, so that an interrupted write never
+        // leaves a partial input in the cache
+        let temp_path = path.with_extension(format!("txt.{}.tmp", process::id()));
+        let write = || {
+            fs::create_dir_all(path.parent().unwrap())?;
+            fs::write(&temp_path, &input)?;
+            fs::rename(&temp_path, &path)
+        };
+        write().map_err(|err| {
+            let _ = fs::remove_file(&temp_path);
+            Error::Io(format!("{}: {err}", path.display()))
+        })?;
+
+        Ok(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{cell::Cell, env, process, rc::Rc};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-inputs-{}-{name}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    struct CountingFetcher(Rc<Cell<usize>>);
+
+    impl Fetcher for CountingFetcher {
+        fn fetch(&self, year: u16, day: u8) -> Result<String> {
+            self.0.set(self.0.get() + 1);
+            Ok(format!("{year} {day}\n"))
+        }
+    }
+
+    #[test]
+    fn cache_fetches_missing_inputs_once() {
+        let dir = temp_dir("cache");
+        let fetches = Rc::new(Cell::new(0));
+        let cache = InputCache::new(&dir).with_fetcher(CountingFetcher(fetches.clone()));
+
+        assert_eq!(cache.load(2020, 7), Ok("2020 7\n".into()));
+        assert_eq!(cache.load(2020, 7), Ok("2020 7\n".into()));
+        assert_eq!(cache.load(2019, 7), Ok("2019 7\n".into()));
+        assert_eq!(fetches.get(), 2);
+        assert_eq!(
+            fs::read_to_string(dir.join("2020").join("day07.txt")).unwrap(),
+            "2020 7\n"
+        );
+        // The temporary file has been renamed into place
+        assert_eq!(fs::read_dir(dir.join("2020")).unwrap().count(), 1);
+
+        assert!(InputCache::new(&dir).load(2020, 7).is_ok());
+        assert!(matches!(
+            InputCache::new(&dir).load(2020, 8),
+            Err(Error::Io(_))
+        ));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    /// Serves one request, returning the request line and headers.
+    #[cfg(feature = "fetch")]
+    fn serve_once(
+        status: &'static str,
+        body: &'static str,
+    ) -> (String, std::thread::JoinHandle<String>) {
+        use std::{
+            io::{BufRead, BufReader, Write},
+            net::TcpListener,
+            thread,
+        };
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = String::new();
+            for line in BufReader::new(stream.try_clone().unwrap()).lines() {
+                let line = line.unwrap();
+                if line.is_empty() {
+                    break;
+                }
+                request.push_str(&line);
+                request.push('\n');
+            }
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            request
+        });
+
+        (base_url, server)
+    }
+
+    #[cfg(feature = "fetch")]
+    #[test]
+    fn http_fetcher_sends_the_session() {
+        let (base_url, server) = serve_once("200 OK", "1721\n979\n");
+        let fetcher = HttpFetcher::with_base_url(base_url, "abc123").unwrap();
+
+        assert_eq!(fetcher.fetch(2020, 1), Ok("1721\n979\n".into()));
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2020/day/1/input HTTP/1.1\n"));
+        assert!(request.contains("Cookie: session=abc123\n"));
+        assert!(request.contains(
+            "User-Agent: advent-of-code (https://github.com/maxdavidson/advent-of-code-2020)\n"
+        ));
+    }
+
+    #[cfg(feature = "fetch")]
+    #[test]
+    fn http_fetcher_reports_failures() {
+        let (base_url, server) = serve_once("400 Bad Request", "Please log in.");
+        let fetcher = HttpFetcher::with_base_url(base_url, "expired").unwrap();
+
+        assert!(matches!(fetcher.fetch(2020, 1), Err(Error::Io(_))));
+        server.join().unwrap();
+    }
+
+    #[cfg(feature = "fetch")]
+    #[test]
+    fn invalid_sessions_are_rejected() {
+        assert!(HttpFetcher::new("53616c7465645f5f").is_ok());
+        for session in ["", "abc\"def", "abc\\", "abc def", "abc;def", "abc\n"] {
+            assert!(
+                matches!(HttpFetcher::new(session), Err(Error::Io(_))),
+                "{session:?}"
+            );
+        }
+    }
+}
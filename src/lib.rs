@@ -1,25 +1,32 @@
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
-pub mod day17;
-pub mod day18;
-pub mod day19;
-pub mod day20;
-pub mod day21;
-pub mod day22;
-pub mod day23;
-pub mod day24;
-pub mod day25;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+pub mod allocations;
+pub mod automaton;
+pub mod bitset;
+pub mod collections;
+pub mod error;
+pub mod graph;
+pub mod grid;
+#[cfg(feature = "std")]
+pub mod inputs;
+pub mod modular;
+pub mod observer;
+#[cfg(feature = "std")]
+pub mod parallel;
+pub mod parser;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod random;
+pub mod reduce;
+pub mod solver;
+#[cfg(feature = "std")]
+pub mod stream;
+pub mod text;
+#[cfg(feature = "std")]
+pub mod verify;
+pub mod y2020;
+
+pub use error::{Error, Result};
@@ -0,0 +1,599 @@
+use std::{
+    env,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read, Write},
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    process::{self, Command, Stdio},
+    thread,
+    time::Instant,
+};
+
+use advent_of_code::{
+    allocations,
+    inputs::InputCache,
+    parallel::{self, Job, Status},
+    random::Rng,
+    reduce,
+    solver::{self, Part, Puzzle, PARTS},
+    text::Text,
+    verify::{self, Outcome},
+    y2020::{day01, day02, day05, day09, day18, day23, day24, day25},
+};
+
+const USAGE: &str = "\
+Usage: aoc <day> <part> [input] [options]
+       aoc verify [manifest]
+       aoc generate <day> [seed] [--year <year>]
+       aoc all [--year <year>] [--inputs <dir> | --cache <dir>] [--threads <count>] [--json]
+       aoc reduce <day> <part> <input> [--year <year>] [--match <pattern> | --reference <command>]
+                  [--output <path>]
+
+Reads the puzzle input from the given file, or from stdin if omitted or `-`.
+
+With `--cache <dir>`, inputs are instead read from `<dir>/<year>/dayNN.txt`. Missing inputs are
+downloaded there first if the `AOC_SESSION` environment variable holds a session cookie, which
+needs the `fetch` feature.
+
+Verify mode checks every answer listed in the manifest (default: answers.txt).
+
+Generate mode prints a random valid input for the day, reproducible from the seed (default: 0).
+
+All mode solves both parts of every day of the year (default: every year) concurrently, reading
+`<dir>/y<year>/dayNN/input.txt` (default dir: src), and prints a table, or JSON with `--json`, of
+the answers, durations and failures.
+
+Reduce mode removes records from an input while the part still panics with the same message, up
+to the numbers in it, or with `--match`, with a message containing the pattern. With
+`--reference`, it instead removes them while the answer differs from what the shell command
+prints when given the input on stdin. The result is written to `--output` (default: the input
+path with the extension `reduced.txt`).
+
+Options:
+    --year <year>        Year of the puzzle (default: the latest year)
+    --preamble <length>  Preamble length for day 9 (default: 25)
+    --moves <count>      Number of moves for day 23 part 1 (default: 100)
+    --stream             Read the input line by line (days 1, 2, 5, 9, 18 and 24)
+    --door <key>         Door public key for day 25
+    --card <key>         Card public key for day 25
+    --cache <dir>        Read the input from the input cache";
+
+struct Args {
+    year: u16,
+    day: u8,
+    part: Part,
+    input_path: Option<String>,
+    preamble_length: usize,
+    moves: usize,
+    door_public_key: Option<u64>,
+    card_public_key: Option<u64>,
+    stream: bool,
+    cache_dir: Option<String>,
+}
+
+fn parse_value<T: std::str::FromStr>(name: &str, value: Option<String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("Missing value for {name}"))?;
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value for {name}: {value}"))
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut positional = Vec::new();
+    let mut year = solver::latest_year();
+    let mut preamble_length = 25;
+    let mut moves = 100;
+    let mut door_public_key = None;
+    let mut card_public_key = None;
+    let mut stream = false;
+    let mut cache_dir = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Err(USAGE.into()),
+            "--year" => year = parse_value(&arg, args.next())?,
+            "--preamble" => preamble_length = parse_value(&arg, args.next())?,
+            "--moves" => moves = parse_value(&arg, args.next())?,
+            "--door" => door_public_key = Some(parse_value(&arg, args.next())?),
+            "--card" => card_public_key = Some(parse_value(&arg, args.next())?),
+            "--stream" => stream = true,
+            "--cache" => cache_dir = Some(parse_value(&arg, args.next())?),
+            flag if flag.starts_with("--") => return Err(format!("Unknown option: {flag}")),
+            _ => positional.push(arg),
+        }
+    }
+
+    let mut positional = positional.into_iter();
+
+    let day = parse_value("<day>", positional.next())?;
+    let part = parse_value("<part>", positional.next())?;
+    let input_path = positional.next().filter(|path| path != "-");
+
+    if let Some(arg) = positional.next() {
+        return Err(format!("Unexpected argument: {arg}"));
+    }
+
+    Ok(Args {
+        year,
+        day,
+        part,
+        input_path,
+        preamble_length,
+        moves,
+        door_public_key,
+        card_public_key,
+        stream,
+        cache_dir,
+    })
+}
+
+fn input_cache(dir: &str) -> Result<InputCache, String> {
+    let cache = InputCache::new(dir);
+    match env::var("AOC_SESSION") {
+        #[cfg(feature = "fetch")]
+        Ok(session) if !session.is_empty() => {
+            let fetcher =
+                advent_of_code::inputs::HttpFetcher::new(session).map_err(|err| err.to_string())?;
+            Ok(cache.with_fetcher(fetcher))
+        }
+        #[cfg(not(feature = "fetch"))]
+        Ok(session) if !session.is_empty() => {
+            Err("AOC_SESSION is set, but downloading inputs needs the `fetch` feature".into())
+        }
+        _ => Ok(cache),
+    }
+}
+
+fn read_input(args: &Args) -> Result<String, String> {
+    let needs_input = (args.year, args.day) != (2020, 25)
+        || args.door_public_key.is_none()
+        || args.card_public_key.is_none();
+
+    match (&args.input_path, &args.cache_dir) {
+        (Some(path), _) => {
+            fs::read_to_string(path).map_err(|err| format!("Failed to read {path}: {err}"))
+        }
+        (None, Some(dir)) if needs_input => input_cache(dir)?
+            .load(args.year, args.day)
+            .map_err(|err| err.to_string()),
+        (None, _) if needs_input => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|err| format!("Failed to read stdin: {err}"))?;
+            Ok(input)
+        }
+        (None, _) => Ok(String::new()),
+    }
+}
+
+fn public_keys(args: &Args, input: &str) -> Result<(u64, u64), String> {
+    let text = Text::new(input);
+    let mut lines = text.lines();
+    let mut next_key = |name: &str, key: Option<u64>| match key {
+        Some(key) => Ok(key),
+        None => parse_value(name, lines.next().map(|line| line.trim().to_owned())),
+    };
+
+    let door_public_key = next_key("door public key", args.door_public_key)?;
+    let card_public_key = next_key("card public key", args.card_public_key)?;
+
+    Ok((door_public_key, card_public_key))
+}
+
+fn solve(args: &Args, input: &str) -> Result<String, String> {
+    let answer = match (args.year, args.day, args.part) {
+        (2020, 9, Part::One) => day09::part1(input, args.preamble_length).map(|n| n.to_string()),
+        (2020, 9, Part::Two) => day09::part2(input, args.preamble_length).map(|n| n.to_string()),
+        (2020, 23, Part::One) => day23::part1(input, args.moves),
+        (2020, 25, Part::One) => {
+            let (door_public_key, card_public_key) = public_keys(args, input)?;
+            day25::part1(door_public_key, card_public_key).map(|n| n.to_string())
+        }
+        (2020, 25, Part::Two) => return Err("Day 25 has no part 2".into()),
+        (year, day, part) => solver::puzzle(year, day)
+            .ok_or_else(|| format!("Invalid day: {day} of {year}"))?
+            .solve(input, part)
+            .transpose()
+            .ok_or_else(|| format!("Day {day} has no part {part}"))?,
+    };
+
+    answer.map_err(|err| err.to_string())
+}
+
+/// The file to read the input from, or `None` for stdin.
+fn input_path(args: &Args) -> Result<Option<PathBuf>, String> {
+    let path = match (&args.input_path, &args.cache_dir) {
+        (Some(path), _) => PathBuf::from(path),
+        (None, Some(dir)) => {
+            let cache = input_cache(dir)?;
+            cache
+                .load(args.year, args.day)
+                .map_err(|err| err.to_string())?;
+            cache.path(args.year, args.day)
+        }
+        (None, None) => return Ok(None),
+    };
+    Ok(Some(path))
+}
+
+fn open_file(path: &Path) -> Result<BufReader<File>, String> {
+    let file =
+        File::open(path).map_err(|err| format!("Failed to read {}: {err}", path.display()))?;
+    Ok(BufReader::new(file))
+}
+
+/// Opens the file at `path`, or stdin if there is none.
+fn open_input(path: Option<&Path>) -> Result<Box<dyn BufRead>, String> {
+    match path {
+        Some(path) => Ok(Box::new(open_file(path)?)),
+        None => Ok(Box::new(io::stdin().lock())),
+    }
+}
+
+fn solve_streaming(args: &Args) -> Result<String, String> {
+    // Resolved once, as a cached input may have to be downloaded first
+    let path = input_path(args)?;
+    let reader = || open_input(path.as_deref());
+
+    let answer = match (args.year, args.day, args.part) {
+        (2020, 1, Part::One) => day01::part1_from_reader(reader()?).map(|n| n.to_string()),
+        (2020, 1, Part::Two) => day01::part2_from_reader(reader()?).map(|n| n.to_string()),
+        (2020, 2, Part::One) => day02::part1_from_reader(reader()?).map(|n| n.to_string()),
+        (2020, 2, Part::Two) => day02::part2_from_reader(reader()?).map(|n| n.to_string()),
+        (2020, 5, Part::One) => day05::part1_from_reader(reader()?).map(|n| n.to_string()),
+        (2020, 5, Part::Two) => day05::part2_from_reader(reader()?).map(|n| n.to_string()),
+        (2020, 9, Part::One) => {
+            day09::part1_from_reader(reader()?, args.preamble_length).map(|n| n.to_string())
+        }
+        (2020, 9, Part::Two) => {
+            let path = path
+                .as_deref()
+                .ok_or("Day 9 part 2 reads its input twice, so it cannot read stdin")?;
+            day09::part2_from_reader(open_file(path)?, args.preamble_length).map(|n| n.to_string())
+        }
+        (2020, 18, Part::One) => day18::part1_from_reader(reader()?).map(|n| n.to_string()),
+        (2020, 18, Part::Two) => day18::part2_from_reader(reader()?).map(|n| n.to_string()),
+        (2020, 24, Part::One) => day24::part1_from_reader(reader()?).map(|n| n.to_string()),
+        (2020, 24, Part::Two) => day24::part2_from_reader(reader()?).map(|n| n.to_string()),
+        (year, day, _) => return Err(format!("Day {day} of {year} cannot be read line by line")),
+    };
+
+    answer.map_err(|err| err.to_string())
+}
+
+fn run_verify(mut args: impl Iterator<Item = String>) -> Result<(), String> {
+    let path = args.next().unwrap_or_else(|| "answers.txt".into());
+    if let Some(arg) = args.next() {
+        return Err(format!("Unexpected argument: {arg}"));
+    }
+
+    let manifest =
+        fs::read_to_string(&path).map_err(|err| format!("Failed to read {path}: {err}"))?;
+    let dir = Path::new(&path).parent().unwrap_or_else(|| Path::new(""));
+    let expectations =
+        verify::parse_manifest(&manifest, dir).map_err(|err| format!("{path}: {err}"))?;
+
+    let total = expectations.len();
+    let mut failures = 0;
+
+    for expectation in expectations {
+        let verification = verify::verify(expectation);
+        let expectation = &verification.expectation;
+        let status = match &verification.outcome {
+            Outcome::Pass => "PASS".to_owned(),
+            Outcome::Fail { actual } => {
+                format!("FAIL (expected {}, got {actual})", expectation.answer)
+            }
+            Outcome::Error(message) => format!("ERROR ({message})"),
+        };
+
+        if verification.outcome != Outcome::Pass {
+            failures += 1;
+        }
+
+        println!(
+            "{} day {:>2}, part {}: {:<46} {:>12?}  {status}",
+            expectation.year,
+            expectation.day,
+            expectation.part,
+            expectation.input.display(),
+            verification.elapsed,
+        );
+        if let Some(stats) = verification.allocations {
+            println!("    {stats}");
+        }
+    }
+
+    println!("{} passed, {failures} failed", total - failures);
+
+    if failures > 0 {
+        return Err(format!("{failures} answer(s) did not match"));
+    }
+
+    Ok(())
+}
+
+fn run_generate(mut args: impl Iterator<Item = String>) -> Result<(), String> {
+    let mut positional = Vec::new();
+    let mut year = solver::latest_year();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = parse_value(&arg, args.next())?,
+            _ => positional.push(arg),
+        }
+    }
+
+    let mut positional = positional.into_iter();
+    let day = parse_value("<day>", positional.next())?;
+    let seed = match positional.next() {
+        Some(seed) => parse_value("[seed]", Some(seed))?,
+        None => 0,
+    };
+    if let Some(arg) = positional.next() {
+        return Err(format!("Unexpected argument: {arg}"));
+    }
+
+    let puzzle =
+        solver::puzzle(year, day).ok_or_else(|| format!("Invalid day: {day} of {year}"))?;
+    println!("{}", (puzzle.generate)(&mut Rng::new(seed)));
+
+    Ok(())
+}
+
+fn run_all(mut args: impl Iterator<Item = String>) -> Result<(), String> {
+    let mut year = None;
+    let mut dir = "src".to_owned();
+    let mut cache = None;
+    let mut threads = thread::available_parallelism().map_or(1, |n| n.get());
+    let mut json = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = Some(parse_value(&arg, args.next())?),
+            "--inputs" => dir = parse_value(&arg, args.next())?,
+            "--cache" => cache = Some(input_cache(&parse_value::<String>(&arg, args.next())?)?),
+            "--threads" => threads = parse_value(&arg, args.next())?,
+            "--json" => json = true,
+            _ => return Err(format!("Unexpected argument: {arg}")),
+        }
+    }
+
+    let mut jobs = Vec::new();
+    let puzzles = solver::puzzles()
+        .iter()
+        .filter(|puzzle| year.map_or(true, |year| puzzle.year == year));
+    for puzzle in puzzles {
+        let input = match &cache {
+            Some(cache) => cache
+                .load(puzzle.year, puzzle.day)
+                .map_err(|err| err.to_string())?,
+            None => {
+                let path = Path::new(&dir)
+                    .join(format!("y{}/day{:02}/input.txt", puzzle.year, puzzle.day));
+                fs::read_to_string(&path)
+                    .map_err(|err| format!("Failed to read {}: {err}", path.display()))?
+            }
+        };
+        for part in PARTS {
+            jobs.push(Job {
+                year: puzzle.year,
+                day: puzzle.day,
+                part,
+                input: input.clone(),
+            });
+        }
+    }
+
+    if let (Some(year), true) = (year, jobs.is_empty()) {
+        return Err(format!("There are no solutions for {year}"));
+    }
+
+    // Panics are reported in the results instead
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let runs = parallel::run_all(&jobs, threads);
+    panic::set_hook(previous_hook);
+
+    if json {
+        print!("{}", parallel::to_json(&runs));
+    } else {
+        print!("{}", parallel::to_table(&runs));
+    }
+
+    let failures = runs
+        .iter()
+        .filter(|run| !matches!(run.status, Status::Solved(_)))
+        .count();
+    if failures > 0 {
+        return Err(format!("{failures} part(s) failed"));
+    }
+
+    Ok(())
+}
+
+/// How a part fails on an input being reduced.
+#[derive(Debug)]
+enum Failure {
+    Panic(String),
+    Mismatch { answer: String, reference: String },
+}
+
+/// Runs the reference command with `input` on stdin, returning its output if it succeeds.
+fn reference_answer(command: &str, input: &str) -> Option<String> {
+    let mut reference = Command::new("sh")
+        .args(["-c", command])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+    // The command may exit without reading all of its input
+    let _ = reference.stdin.take().unwrap().write_all(input.as_bytes());
+
+    let output = reference.wait_with_output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_owned())
+}
+
+/// Replaces every number in a panic message with `N`, since messages such as `index out of bounds:
+/// the len is 12 but the index is 40` change as the input shrinks.
+fn without_numbers(message: &str) -> String {
+    let mut normalized = String::with_capacity(message.len());
+    let mut in_number = false;
+    for c in message.chars() {
+        if !c.is_ascii_digit() {
+            normalized.push(c);
+        } else if !in_number {
+            normalized.push('N');
+        }
+        in_number = c.is_ascii_digit();
+    }
+    normalized
+}
+
+fn failure(puzzle: &Puzzle, part: Part, input: &str, reference: Option<&str>) -> Option<Failure> {
+    // The solvers hold no state that a panic could leave broken
+    let solve = AssertUnwindSafe(|| puzzle.solve(input, part));
+
+    match (parallel::catch_panic(solve), reference) {
+        (Err(message), None) => Some(Failure::Panic(message)),
+        (Ok(Ok(Some(answer))), Some(command)) => {
+            let reference = reference_answer(command, input)?;
+            (answer != reference).then_some(Failure::Mismatch { answer, reference })
+        }
+        _ => None,
+    }
+}
+
+fn run_reduce(mut args: impl Iterator<Item = String>) -> Result<(), String> {
+    let mut positional = Vec::new();
+    let mut year = solver::latest_year();
+    let mut reference = None;
+    let mut pattern = None;
+    let mut output = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = parse_value(&arg, args.next())?,
+            "--reference" => reference = Some(parse_value::<String>(&arg, args.next())?),
+            "--match" => pattern = Some(parse_value::<String>(&arg, args.next())?),
+            "--output" => output = Some(parse_value::<PathBuf>(&arg, args.next())?),
+            _ => positional.push(arg),
+        }
+    }
+
+    let mut positional = positional.into_iter();
+    let day = parse_value("<day>", positional.next())?;
+    let part = parse_value("<part>", positional.next())?;
+    let path: PathBuf = parse_value("<input>", positional.next())?;
+    if let Some(arg) = positional.next() {
+        return Err(format!("Unexpected argument: {arg}"));
+    }
+    if pattern.is_some() && reference.is_some() {
+        return Err("--match and --reference cannot be combined".into());
+    }
+    let output = output.unwrap_or_else(|| path.with_extension("reduced.txt"));
+
+    let puzzle =
+        solver::puzzle(year, day).ok_or_else(|| format!("Invalid day: {day} of {year}"))?;
+    let input = fs::read_to_string(&path)
+        .map_err(|err| format!("Failed to read {}: {err}", path.display()))?;
+
+    // Panics are what is being reproduced, so they are not printed
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let reference = reference.as_deref();
+    let original = failure(puzzle, part, &input, reference);
+    // Panics match if they contain the pattern, or else have the same message up to numbers
+    let same_panic = |message: &str, original: &str| match &pattern {
+        Some(pattern) => message.contains(pattern.as_str()),
+        None => without_numbers(message) == without_numbers(original),
+    };
+    let mut runs = 1;
+    let reduced = original.map(|original| {
+        let reduced = reduce::reduce(&input, puzzle.solver.structure(), |candidate| {
+            runs += 1;
+            match (failure(puzzle, part, candidate, reference), &original) {
+                (Some(Failure::Panic(message)), Failure::Panic(original)) => {
+                    same_panic(&message, original)
+                }
+                (Some(Failure::Mismatch { .. }), Failure::Mismatch { .. }) => true,
+                _ => false,
+            }
+        });
+        let reproduced = failure(puzzle, part, &reduced, reference);
+        (reduced, reproduced)
+    });
+
+    panic::set_hook(previous_hook);
+
+    let Some((reduced, reproduced)) = reduced else {
+        return Err(match reference {
+            Some(_) => format!("Day {day}, part {part} agrees with the reference on the input"),
+            None => format!("Day {day}, part {part} does not panic on the input"),
+        });
+    };
+
+    match reproduced {
+        Some(Failure::Panic(message)) => println!("Panics: {message}"),
+        Some(Failure::Mismatch { answer, reference }) => {
+            println!("Answers {answer}, but the reference answers {reference}")
+        }
+        None => {}
+    }
+
+    fs::write(&output, &reduced)
+        .map_err(|err| format!("Failed to write {}: {err}", output.display()))?;
+    println!(
+        "Reduced {} lines to {} in {runs} runs, written to {}",
+        input.lines().count(),
+        reduced.lines().count(),
+        output.display()
+    );
+
+    Ok(())
+}
+
+fn run() -> Result<(), String> {
+    let mut args = env::args().skip(1).peekable();
+    match args.peek().map(String::as_str) {
+        Some("verify") => return run_verify(args.skip(1)),
+        Some("generate") => return run_generate(args.skip(1)),
+        Some("all") => return run_all(args.skip(1)),
+        Some("reduce") => return run_reduce(args.skip(1)),
+        _ => {}
+    }
+
+    let args = parse_args(args)?;
+
+    let ((answer, stats), elapsed) = if args.stream {
+        let start = Instant::now();
+        let answer = allocations::measure(|| solve_streaming(&args));
+        (answer, start.elapsed())
+    } else {
+        let input = read_input(&args)?;
+        let start = Instant::now();
+        let answer = allocations::measure(|| solve(&args, &input));
+        (answer, start.elapsed())
+    };
+
+    println!("Day {}, part {}: {}", args.day, args.part, answer?);
+    println!("Elapsed: {:?}", elapsed);
+    if let Some(stats) = stats {
+        println!("Allocations: {stats}");
+    }
+
+    Ok(())
+}
+
+fn main() {
+    if let Err(message) = run() {
+        eprintln!("{message}");
+        process::exit(1);
+    }
+}
@@ -0,0 +1,221 @@
+//! Number theory for modular arithmetic on `u64`s, which computes in `u128` where products could
+//! overflow.
+//!
+//! Every modulus must be positive.
+
+use crate::collections::HashMap;
+
+pub fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Returns the greatest common divisor `g` of `a` and `b`, along with `x` and `y` such that
+/// `a * x + b * y = g`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    (old_r, old_x, old_y)
+}
+
+/// The `x` below `m` such that `a * x ≡ 1 (mod m)`, if `a` and `m` are coprime.
+pub fn inverse(a: u64, m: u64) -> Option<u64> {
+    let (g, x, _) = extended_gcd(i128::from(a % m), i128::from(m));
+    (g == 1).then(|| x.rem_euclid(i128::from(m)) as u64)
+}
+
+pub fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (u128::from(a) * u128::from(b) % u128::from(m)) as u64
+}
+
+pub fn pow_mod(mut base: u64, mut exponent: u64, m: u64) -> u64 {
+    let mut result = 1 % m;
+    base %= m;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exponent >>= 1;
+    }
+
+    result
+}
+
+/// Finds the `x` that is congruent to every residue modulo its modulus, given as
+/// `(residue, modulus)` pairs, which need not be coprime.
+///
+/// Returns the smallest such `x` along with the least common multiple of the moduli, which all
+/// the others differ from `x` by multiples of. Returns `None` if there is no such `x`, or if the
+/// least common multiple does not fit in a `u64`.
+pub fn crt(congruences: impl IntoIterator<Item = (u64, u64)>) -> Option<(u64, u64)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(x, lcm), (residue, modulus)| {
+            // Solves `x + lcm * t ≡ residue (mod modulus)` for the smallest `t`
+            let g = gcd(lcm, modulus);
+            let difference = (u128::from(residue % modulus) + u128::from(modulus)
+                - u128::from(x % modulus))
+                % u128::from(modulus);
+            let difference = difference as u64;
+            if difference % g != 0 {
+                return None;
+            }
+            let reduced_modulus = modulus / g;
+            let t = mul_mod(
+                difference / g,
+                inverse(lcm / g, reduced_modulus)?,
+                reduced_modulus,
+            );
+
+            let next_lcm = lcm.checked_mul(reduced_modulus)?;
+            // `t` is below `reduced_modulus`, so this is below `next_lcm`
+            Some((x + lcm * t, next_lcm))
+        })
+}
+
+/// The largest modulus that [`discrete_log`] accepts, which keeps its table to about a million
+/// entries.
+pub const MAX_DISCRETE_LOG_MODULUS: u64 = 1 << 40;
+
+/// The smallest `x` such that `base^x ≡ target (mod m)`, if there is one.
+///
+/// Uses baby-step giant-step, which takes time and space around the square root of `m`. Common
+/// factors of `base` and `m` are divided out first, so they need not be coprime. Returns `None`
+/// without trying if `m` is above [`MAX_DISCRETE_LOG_MODULUS`].
+pub fn discrete_log(base: u64, target: u64, mut m: u64) -> Option<u64> {
+    if m > MAX_DISCRETE_LOG_MODULUS {
+        return None;
+    }
+
+    let (mut base, mut target) = (base % m, target % m);
+    // Solves `coefficient * base^x ≡ target (mod m)`, where `x` is offset by `steps`
+    let mut coefficient = 1 % m;
+    let mut steps = 0;
+
+    loop {
+        if coefficient == target {
+            return Some(steps);
+        }
+        let g = gcd(base, m);
+        if g == 1 {
+            break;
+        }
+        if target % g != 0 {
+            return None;
+        }
+        target /= g;
+        m /= g;
+        coefficient = mul_mod(coefficient, base / g, m);
+        steps += 1;
+    }
+    base %= m;
+
+    // The number of steps of each kind, whose square is at least `m`
+    let mut n = 1;
+    while u128::from(n) * u128::from(n) < u128::from(m) {
+        n += 1;
+    }
+
+    // `target * base^j` for the baby steps `j`, keeping the largest `j` for each value
+    let mut baby_steps = HashMap::default();
+    let mut value = target;
+    for j in 0..=n {
+        baby_steps.insert(value, j);
+        value = mul_mod(value, base, m);
+    }
+
+    // `coefficient * base^(n * i)` for the giant steps `i`
+    let giant_step = pow_mod(base, n, m);
+    let mut value = coefficient;
+    for i in 1..=n {
+        value = mul_mod(value, giant_step, m);
+        if let Some(j) = baby_steps.get(&value) {
+            return Some(steps + i * n - j);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inverses_exist_for_coprime_numbers() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(extended_gcd(240, 46), (2, -9, 47));
+        assert_eq!(inverse(3, 11), Some(4));
+        assert_eq!(inverse(14, 11), Some(4));
+        assert_eq!(inverse(6, 9), None);
+        assert_eq!(inverse(5, 1), Some(0));
+    }
+
+    #[test]
+    fn products_do_not_overflow() {
+        assert_eq!(mul_mod(u64::MAX - 1, u64::MAX - 1, u64::MAX), 1);
+        assert_eq!(pow_mod(2, 10, 1000), 24);
+        assert_eq!(pow_mod(u64::MAX - 1, u64::MAX, u64::MAX), u64::MAX - 1);
+        assert_eq!(pow_mod(5, 0, 1), 0);
+    }
+
+    #[test]
+    fn crt_allows_common_factors() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(0, 6), (2, 4)]), Some((6, 12)));
+        assert_eq!(crt([(0, 2), (1, 4)]), None);
+        assert_eq!(crt([]), Some((0, 1)));
+        assert_eq!(crt([(0, 1 << 40), (0, (1 << 40) - 1)]), None);
+    }
+
+    #[test]
+    fn crt_handles_large_moduli() {
+        let max = u64::MAX;
+        assert_eq!(crt([(max - 1, max), (max - 1, max)]), Some((max - 1, max)));
+        assert_eq!(crt([(max - 1, max), (max - 2, max)]), None);
+        assert_eq!(crt([(0, max), (1, max - 1)]), None);
+        assert_eq!(
+            crt([(1, 1 << 32), (max - 1, (1 << 32) - 1)]),
+            Some((18_446_744_060_824_649_729, max - (1 << 32) + 1))
+        );
+    }
+
+    #[test]
+    fn discrete_log_finds_the_smallest_exponent() {
+        assert_eq!(discrete_log(7, 5_764_801, 20_201_227), Some(8));
+        assert_eq!(discrete_log(2, 1, 11), Some(0));
+        assert_eq!(discrete_log(2, 3, 11), Some(8));
+        assert_eq!(discrete_log(4, 2, 7), Some(2));
+        assert_eq!(discrete_log(3, 2, 7), Some(2));
+        // 2 has no inverse modulo 12, but 2^2 ≡ 4 and 2^3 ≡ 8
+        assert_eq!(discrete_log(2, 8, 12), Some(3));
+        assert_eq!(discrete_log(2, 3, 12), None);
+        assert_eq!(discrete_log(0, 0, 5), Some(1));
+    }
+
+    #[test]
+    fn discrete_log_gives_up_above_the_limit() {
+        // 3 has order 2^38 modulo 2^40
+        let target = pow_mod(3, 123_456_789, MAX_DISCRETE_LOG_MODULUS);
+        assert_eq!(
+            discrete_log(3, target, MAX_DISCRETE_LOG_MODULUS),
+            Some(123_456_789)
+        );
+        assert_eq!(discrete_log(3, 3, MAX_DISCRETE_LOG_MODULUS + 1), None);
+        assert_eq!(discrete_log(1, 1, u64::MAX), None);
+    }
+}
@@ -0,0 +1,100 @@
+use alloc::{borrow::ToOwned, vec::Vec};
+use core::ops::ControlFlow;
+
+use crate::{Error, Result};
+
+/// Receives each intermediate state of a simulation, and can stop it early.
+///
+/// Closures taking a state and returning a [`ControlFlow`] are observers.
+pub trait Observer<S: ?Sized> {
+    /// Called after each step of the simulation.
+    fn observe(&mut self, state: &S) -> ControlFlow<()>;
+}
+
+impl<S: ?Sized, F: FnMut(&S) -> ControlFlow<()>> Observer<S> for F {
+    fn observe(&mut self, state: &S) -> ControlFlow<()> {
+        self(state)
+    }
+}
+
+/// Lets the simulation run to the end, which is how the parts are solved.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Ignore;
+
+impl<S: ?Sized> Observer<S> for Ignore {
+    #[inline]
+    fn observe(&mut self, _: &S) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+}
+
+/// Records a copy of every state.
+#[derive(Debug, Clone)]
+pub struct Trace<T> {
+    pub states: Vec<T>,
+}
+
+impl<T> Trace<T> {
+    pub fn new() -> Self {
+        Trace { states: Vec::new() }
+    }
+}
+
+impl<T> Default for Trace<T> {
+    fn default() -> Self {
+        Trace::new()
+    }
+}
+
+impl<S: ToOwned + ?Sized> Observer<S> for Trace<S::Owned> {
+    fn observe(&mut self, state: &S) -> ControlFlow<()> {
+        self.states.push(state.to_owned());
+        ControlFlow::Continue(())
+    }
+}
+
+/// Passes `state` to `observer`, returning `Error::Stopped` if it stops the simulation.
+#[inline]
+pub(crate) fn notify<S: ?Sized>(observer: &mut impl Observer<S>, state: &S) -> Result<()> {
+    match observer.observe(state) {
+        ControlFlow::Continue(()) => Ok(()),
+        ControlFlow::Break(()) => Err(Error::Stopped),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count_to(limit: u32, observer: &mut impl Observer<u32>) -> Result<u32> {
+        for n in 1..=limit {
+            notify(observer, &n)?;
+        }
+        Ok(limit)
+    }
+
+    #[test]
+    fn observers_see_every_state() {
+        let mut trace = Trace::new();
+        assert_eq!(count_to(3, &mut trace), Ok(3));
+        assert_eq!(trace.states, [1, 2, 3]);
+
+        assert_eq!(count_to(3, &mut Ignore), Ok(3));
+    }
+
+    #[test]
+    fn observers_can_stop_early() {
+        let mut seen = Vec::new();
+        let mut stop_at_two = |n: &u32| {
+            seen.push(*n);
+            if *n == 2 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        };
+
+        assert_eq!(count_to(3, &mut stop_at_two), Err(Error::Stopped));
+        assert_eq!(seen, [1, 2]);
+    }
+}
@@ -0,0 +1,248 @@
+use std::{
+    any::Any,
+    fmt::Write as _,
+    panic::{self, AssertUnwindSafe, UnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::solver::{self, Part};
+
+/// A day and part to solve for an input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Job {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub input: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Solved(String),
+    Failed(String),
+    Panicked(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub status: Status,
+    pub elapsed: Duration,
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => (*message).into(),
+            Err(_) => "Unknown panic".into(),
+        },
+    }
+}
+
+/// Runs `f`, returning the message of its panic as an error if it panics.
+pub fn catch_panic<T>(f: impl FnOnce() -> T + UnwindSafe) -> Result<T, String> {
+    panic::catch_unwind(f).map_err(panic_message)
+}
+
+fn run(job: &Job) -> Option<Run> {
+    let puzzle = solver::puzzle(job.year, job.day)?;
+    let start = Instant::now();
+
+    // The solvers hold no state that a panic could leave broken
+    let solve = AssertUnwindSafe(|| puzzle.solve(&job.input, job.part));
+
+    let status = match catch_panic(solve) {
+        Ok(Ok(Some(answer))) => Status::Solved(answer),
+        Ok(Ok(None)) => return None,
+        Ok(Err(err)) => Status::Failed(err.to_string()),
+        Err(message) => Status::Panicked(message),
+    };
+
+    Some(Run {
+        year: job.year,
+        day: job.day,
+        part: job.part,
+        status,
+        elapsed: start.elapsed(),
+    })
+}
+
+/// Solves the jobs on `threads` threads, returning the runs sorted by year, day and part. A panic is
+/// reported as the status of its run, and jobs for parts that do not exist are left out.
+pub fn run_all(jobs: &[Job], threads: usize) -> Vec<Run> {
+    let next_job = AtomicUsize::new(0);
+    let runs = Mutex::new(Vec::with_capacity(jobs.len()));
+
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, jobs.len().max(1)) {
+            scope.spawn(|| {
+                while let Some(job) = jobs.get(next_job.fetch_add(1, Ordering::Relaxed)) {
+                    if let Some(run) = run(job) {
+                        runs.lock().unwrap().push(run);
+                    }
+                }
+            });
+        }
+    });
+
+    let mut runs = runs.into_inner().unwrap();
+    runs.sort_by_key(|run| (run.year, run.day, run.part));
+    runs
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            c if c.is_control() => write!(json, "\\u{:04x}", c as u32).unwrap(),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+pub fn to_json(runs: &[Run]) -> String {
+    let mut json = String::from("[\n");
+
+    for (index, run) in runs.iter().enumerate() {
+        let (status, value_key, value) = match &run.status {
+            Status::Solved(answer) => ("solved", "answer", answer),
+            Status::Failed(message) => ("failed", "error", message),
+            Status::Panicked(message) => ("panicked", "error", message),
+        };
+        let separator = if index + 1 < runs.len() { "," } else { "" };
+        writeln!(
+            json,
+            r#"  {{"year": {}, "day": {}, "part": {}, "status": "{status}", "{value_key}": {}, "elapsed_ns": {}}}{separator}"#,
+            run.year,
+            run.day,
+            run.part,
+            json_string(value),
+            run.elapsed.as_nanos(),
+        )
+        .unwrap();
+    }
+
+    json.push_str("]\n");
+    json
+}
+
+pub fn to_table(runs: &[Run]) -> String {
+    let mut table = format!(
+        "{:>4}  {:>3}  {:>4}  {:>12}  {:<8}  answer\n",
+        "year", "day", "part", "elapsed", "status"
+    );
+
+    for run in runs {
+        let (status, value) = match &run.status {
+            Status::Solved(answer) => ("solved", answer),
+            Status::Failed(message) => ("failed", message),
+            Status::Panicked(message) => ("panicked", message),
+        };
+        writeln!(
+            table,
+            "{:>4}  {:>3}  {:>4}  {:>12?}  {status:<8}  {value}",
+            run.year, run.day, run.part, run.elapsed
+        )
+        .unwrap();
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        random::Rng,
+        solver::{Puzzle, PARTS},
+    };
+
+    fn jobs(input: impl Fn(&Puzzle) -> String) -> Vec<Job> {
+        solver::puzzles()
+            .iter()
+            .flat_map(|puzzle| {
+                PARTS.map(|part| Job {
+                    year: puzzle.year,
+                    day: puzzle.day,
+                    part,
+                    input: input(puzzle),
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn run_all_sorts_runs() {
+        let runs = run_all(&jobs(|_| "x".into()), 4);
+
+        assert_eq!(runs.len(), 2 * solver::puzzles().len());
+        assert!(runs
+            .windows(2)
+            .all(|pair| (pair[0].year, pair[0].day, pair[0].part)
+                < (pair[1].year, pair[1].day, pair[1].part)));
+        assert!(runs
+            .iter()
+            .all(|run| !matches!(run.status, Status::Panicked(_))));
+    }
+
+    #[test]
+    fn run_all_solves_days_and_skips_missing_parts() {
+        let jobs: Vec<Job> = jobs(|puzzle| (puzzle.generate)(&mut Rng::new(u64::from(puzzle.day))))
+            .into_iter()
+            .filter(|job| [1, 6, 25].contains(&job.day))
+            .collect();
+        let runs = run_all(&jobs, 2);
+
+        assert_eq!(runs.len(), 5);
+        for (run, job) in runs.iter().zip(&jobs) {
+            let expected = solver::puzzle(job.year, job.day)
+                .unwrap()
+                .solve(&job.input, job.part)
+                .unwrap()
+                .unwrap();
+            assert_eq!(run.status, Status::Solved(expected));
+        }
+    }
+
+    #[test]
+    fn catch_panic_reports_the_message() {
+        assert_eq!(
+            catch_panic(|| -> u32 { panic!("day {} exploded", 99) }),
+            Err("day 99 exploded".into())
+        );
+        assert_eq!(
+            catch_panic(|| -> u32 { panic!("exploded") }),
+            Err("exploded".into())
+        );
+        assert_eq!(catch_panic(|| 42), Ok(42));
+    }
+
+    #[test]
+    fn json_escapes_strings() {
+        let runs = [Run {
+            year: 2020,
+            day: 1,
+            part: Part::Two,
+            status: Status::Failed("Invalid value \"x\"\n".into()),
+            elapsed: Duration::from_nanos(5),
+        }];
+
+        assert_eq!(
+            to_json(&runs),
+            "[\n  {\"year\": 2020, \"day\": 1, \"part\": 2, \"status\": \"failed\", \"error\": \"Invalid value \\\"x\\\"\\n\", \"elapsed_ns\": 5}\n]\n"
+        );
+    }
+}
@@ -0,0 +1,249 @@
+use alloc::{format, string::String, vec, vec::Vec};
+use core::{fmt, str::FromStr};
+
+use crate::{error::parse_at, Error, Result};
+
+/// A cursor over a slice of the input, which reports errors at their position in the whole input.
+///
+/// Parsers are small functions taking a `&mut Parser`, which advance it past what they consumed.
+#[derive(Debug, Clone, Copy)]
+pub struct Parser<'a> {
+    input: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Parser { input, rest: input }
+    }
+
+    /// Parses `fragment`, which must be a slice of `input`.
+    pub fn within(input: &'a str, fragment: &'a str) -> Self {
+        Parser {
+            input,
+            rest: fragment,
+        }
+    }
+
+    /// The text that is left to parse.
+    pub fn rest(&self) -> &'a str {
+        self.rest
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    /// Creates an error at the current position.
+    pub fn error(&self, message: impl Into<String>) -> Error {
+        Error::at(self.input, self.rest, message)
+    }
+
+    fn advance(&mut self, len: usize) -> &'a str {
+        let (taken, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        taken
+    }
+
+    /// Consumes `tag` if the text starts with it.
+    pub fn eat(&mut self, tag: &str) -> bool {
+        let found = self.rest.starts_with(tag);
+        if found {
+            self.advance(tag.len());
+        }
+        found
+    }
+
+    pub fn tag(&mut self, tag: &str) -> Result<()> {
+        if self.eat(tag) {
+            Ok(())
+        } else {
+            Err(self.error(format!("Expected `{tag}`")))
+        }
+    }
+
+    /// Consumes the longest prefix of characters matching `predicate`, which may be empty.
+    pub fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let len = self.rest.find(|c| !predicate(c)).unwrap_or(self.rest.len());
+        self.advance(len)
+    }
+
+    /// Consumes the text up to the next `delimiter`, which is left in place.
+    pub fn take_until(&mut self, delimiter: &str) -> Result<&'a str> {
+        match self.rest.find(delimiter) {
+            Some(len) => Ok(self.advance(len)),
+            None => Err(self.error(format!("Expected `{delimiter}`"))),
+        }
+    }
+
+    pub fn char(&mut self) -> Result<char> {
+        let c = self
+            .rest
+            .chars()
+            .next()
+            .ok_or_else(|| self.error("Expected a character"))?;
+        self.advance(c.len_utf8());
+        Ok(c)
+    }
+
+    /// Consumes a non-empty run of letters and digits.
+    pub fn word(&mut self) -> Result<&'a str> {
+        let word = self.take_while(char::is_alphanumeric);
+        if word.is_empty() {
+            return Err(self.error("Expected a word"));
+        }
+        Ok(word)
+    }
+
+    /// Consumes a decimal number with an optional sign, reporting values out of range.
+    pub fn number<T>(&mut self) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let sign_len = usize::from(self.rest.starts_with(['-', '+']));
+        let digits_len = self.rest[sign_len..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(self.rest.len() - sign_len);
+        if digits_len == 0 {
+            return Err(self.error("Expected a number"));
+        }
+
+        let number = self.advance(sign_len + digits_len);
+        parse_at(self.input, number)
+    }
+
+    /// Parses one or more items separated by `separator`.
+    ///
+    /// A separator is only consumed if an item follows it. An item that fails before consuming
+    /// anything ends the list, while one that fails after consuming some text fails the list.
+    pub fn separated<T>(
+        &mut self,
+        separator: &str,
+        mut item: impl FnMut(&mut Self) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        let mut items = vec![item(self)?];
+
+        loop {
+            let mut next = *self;
+            if !next.eat(separator) {
+                break;
+            }
+
+            let start = next.rest.len();
+            match item(&mut next) {
+                Ok(value) => items.push(value),
+                Err(err) if next.rest.len() != start => return Err(err),
+                Err(_) => break,
+            }
+            *self = next;
+        }
+
+        Ok(items)
+    }
+
+    pub fn end(&self) -> Result<()> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error(format!("Unexpected `{}`", self.rest)))
+        }
+    }
+
+    /// Parses each line of the text with `line`, which must consume the whole line.
+    pub fn lines<T>(self, mut line: impl FnMut(&mut Self) -> Result<T>) -> Result<Vec<T>> {
+        self.rest
+            .lines()
+            .map(|text| {
+                let mut parser = Parser::within(self.input, text);
+                let value = line(&mut parser)?;
+                parser.end()?;
+                Ok(value)
+            })
+            .collect()
+    }
+
+    /// Splits the text into blocks separated by blank lines.
+    pub fn blocks(self) -> impl Iterator<Item = Parser<'a>> {
+        self.rest
+            .split("\n\n")
+            .filter(|block| !block.trim().is_empty())
+            .map(move |block| Parser::within(self.input, block))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsers_consume_what_they_match() {
+        let mut p = Parser::new("12-34 ab: -5,6,7 rest");
+
+        assert_eq!(p.number(), Ok(12u32));
+        p.tag("-").unwrap();
+        assert_eq!(p.number(), Ok(34u32));
+        assert!(p.eat(" "));
+        assert!(!p.eat(" "));
+        assert_eq!(p.word(), Ok("ab"));
+        assert_eq!(p.char(), Ok(':'));
+        assert_eq!(p.take_while(char::is_whitespace), " ");
+        assert_eq!(p.separated(",", Parser::number), Ok(vec![-5i32, 6, 7]));
+        assert_eq!(p.take_until("st"), Ok(" re"));
+        assert_eq!(p.rest(), "st");
+    }
+
+    #[test]
+    fn separated_stops_before_separators_without_items() {
+        let mut p = Parser::new("1 2 | 3 4");
+        let alternatives = p.separated(" | ", |p| p.separated(" ", Parser::number::<u8>));
+
+        assert_eq!(alternatives, Ok(vec![vec![1, 2], vec![3, 4]]));
+        assert!(p.is_empty());
+
+        let mut p = Parser::new("a b (c)");
+        assert_eq!(p.separated(" ", Parser::word), Ok(vec!["a", "b"]));
+        assert_eq!(p.rest(), " (c)");
+    }
+
+    #[test]
+    fn lines_and_blocks_report_positions_in_the_input() {
+        let input = "a: 1\nb: 2\n\nc: 3\nd: x\n";
+        let mut blocks = Parser::new(input).blocks();
+
+        let first = blocks.next().unwrap().lines(|p| {
+            let key = p.word()?;
+            p.tag(": ")?;
+            Ok((key, p.number::<u8>()?))
+        });
+        assert_eq!(first, Ok(vec![("a", 1), ("b", 2)]));
+
+        let second = blocks.next().unwrap().lines(|p| p.word());
+        assert_eq!(second, Err(Error::parse(4, 2, "Unexpected `: 3`")));
+        assert!(blocks.next().is_none());
+    }
+
+    #[test]
+    fn errors_are_reported_at_their_position() {
+        let input = "ok\n  x 99999999999";
+
+        let mut p = Parser::within(input, &input[3..]);
+        assert_eq!(p.tag("y"), Err(Error::parse(2, 1, "Expected `y`")));
+        p.take_while(char::is_whitespace);
+        assert_eq!(
+            p.number::<u8>(),
+            Err(Error::parse(2, 3, "Expected a number"))
+        );
+        assert_eq!(p.take_until("z"), Err(Error::parse(2, 3, "Expected `z`")));
+        p.tag("x ").unwrap();
+        assert!(matches!(
+            p.number::<u32>(),
+            Err(Error::Parse {
+                line: 2,
+                column: 5,
+                ..
+            })
+        ));
+        assert_eq!(p.end(), Ok(()));
+    }
+}
@@ -0,0 +1,173 @@
+//! Python bindings, built with the `python` feature.
+//!
+//! Each year is a submodule of days, which have `part1` and `part2` functions taking the input as
+//! a string, and errors are raised as `ValueError`.
+
+use pyo3::{
+    exceptions::PyValueError,
+    prelude::*,
+    types::{IntoPyDict, PyDict},
+    wrap_pyfunction,
+};
+
+use crate::{solver::Solver, text::Text, Error};
+
+impl From<Error> for PyErr {
+    fn from(err: Error) -> Self {
+        PyValueError::new_err(err.to_string())
+    }
+}
+
+/// Returns the bags each bag directly contains, with their counts.
+#[pyfunction]
+fn bag_graph(py: Python, input: &str) -> PyResult<Py<PyDict>> {
+    let graph = PyDict::new(py);
+    for (color, contents) in crate::y2020::day07::Solution::parse(&Text::new(input))?.0 {
+        graph.set_item(
+            color,
+            contents.into_iter().collect::<Vec<_>>().into_py_dict(py),
+        )?;
+    }
+    Ok(graph.into())
+}
+
+/// Returns the rows of the assembled image, with `#` for filled pixels.
+#[pyfunction]
+fn image(py: Python, input: &str) -> PyResult<Vec<String>> {
+    let image = py.allow_threads(|| crate::y2020::day20::image(input))?;
+    Ok(image
+        .rows()
+        .map(|row| {
+            row.iter()
+                .map(|filled| if *filled { '#' } else { '.' })
+                .collect()
+        })
+        .collect())
+}
+
+macro_rules! days {
+    ($year:ident { $($day:ident $([$($function:ident),*])?),* $(,)? }) => {
+        $(
+            mod $day {
+                use super::*;
+                use crate::$year::$day::Solution;
+
+                #[pyfunction]
+                fn part1(py: Python, input: &str) -> PyResult<PyObject> {
+                    let answer = py.allow_threads(|| {
+                        Solution::part1(&Solution::parse(&Text::new(input))?)
+                    })?;
+                    Ok(answer.into_py(py))
+                }
+
+                #[pyfunction]
+                fn part2(py: Python, input: &str) -> PyResult<PyObject> {
+                    let answer = py.allow_threads(|| {
+                        Solution::part2(&Solution::parse(&Text::new(input))?).transpose()
+                    })?;
+                    let answer = answer.ok_or_else(|| {
+                        PyValueError::new_err(concat!(stringify!($day), " has no part 2"))
+                    })?;
+                    Ok(answer.into_py(py))
+                }
+
+                pub fn module(py: Python) -> PyResult<&PyModule> {
+                    let module = PyModule::new(py, stringify!($day))?;
+                    module.add_function(wrap_pyfunction!(part1, module)?)?;
+                    module.add_function(wrap_pyfunction!(part2, module)?)?;
+                    $($(module.add_function(wrap_pyfunction!(super::$function, module)?)?;)*)?
+                    Ok(module)
+                }
+            }
+        )*
+
+        fn $year(py: Python) -> PyResult<&PyModule> {
+            let module = PyModule::new(py, stringify!($year))?;
+            $(module.add_submodule($day::module(py)?)?;)*
+            Ok(module)
+        }
+    };
+}
+
+days!(y2020 {
+    day01, day02, day03, day04, day05, day06, day07 [bag_graph], day08, day09, day10, day11,
+    day12, day13, day14, day15, day16, day17, day18, day19, day20 [image], day21, day22, day23,
+    day24, day25,
+});
+
+#[pymodule]
+fn advent_of_code(py: Python, module: &PyModule) -> PyResult<()> {
+    module.add_submodule(y2020(py)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(code: &str, inputs: &[(&str, &str)]) {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let module = PyModule::new(py, "advent_of_code").unwrap();
+            advent_of_code(py, module).unwrap();
+
+            let locals = inputs.into_py_dict(py);
+            locals.set_item("aoc", module).unwrap();
+            if let Err(err) = py.run(code, None, Some(locals)) {
+                err.print(py);
+                panic!("{err}");
+            }
+        });
+    }
+
+    #[test]
+    fn parts_return_answers() {
+        run(
+            r#"
+assert aoc.y2020.day01.part1(day01) == 514579
+assert aoc.y2020.day01.part2(day01) == 241861950
+assert aoc.y2020.day21.part2(day21) == "mxmxvkd,sqjhc,fvjkl"
+assert aoc.y2020.day25.part1(day25) == 14897079
+"#,
+            &[
+                ("day01", include_str!("y2020/day01/test_input.txt")),
+                ("day21", include_str!("y2020/day21/test_input.txt")),
+                ("day25", include_str!("y2020/day25/test_input.txt")),
+            ],
+        );
+    }
+
+    #[test]
+    fn errors_are_raised_as_value_errors() {
+        run(
+            r#"
+for solve in [aoc.y2020.day01.part1, aoc.y2020.day25.part2]:
+    try:
+        solve("x")
+    except ValueError as err:
+        assert "line 1" in str(err), err
+    else:
+        assert False
+"#,
+            &[],
+        );
+    }
+
+    #[test]
+    fn parsed_structures_are_exposed() {
+        run(
+            r#"
+graph = aoc.y2020.day07.bag_graph(day07)
+assert graph["light red"] == {"bright white": 1, "muted yellow": 2}
+assert graph["faded blue"] == {}
+
+image = aoc.y2020.day20.image(day20)
+assert len(image) == 24 and all(len(row) == 24 for row in image)
+assert sum(row.count('#') for row in image) == 303
+"#,
+            &[
+                ("day07", include_str!("y2020/day07/test_input.txt")),
+                ("day20", include_str!("y2020/day20/test_input.txt")),
+            ],
+        );
+    }
+}
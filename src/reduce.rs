@@ -0,0 +1,209 @@
+//! Shrinks inputs that make a solver fail, while keeping the failure.
+
+use alloc::{string::String, vec, vec::Vec};
+
+use crate::text::Text;
+
+/// How a day's input splits into records, which the reducer removes independently.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Structure {
+    /// Every line is a record.
+    Lines,
+    /// Groups of lines separated by blank lines are records, and so are the lines in them if
+    /// `lines` is set.
+    Blocks { lines: bool },
+    /// Sections separated by blank lines, which are always kept along with at least one of their
+    /// lines, with a record on every line.
+    Sections,
+}
+
+type Groups<'a> = Vec<Vec<&'a str>>;
+
+/// Splits a normalized input, whose blank lines are empty and whose only line endings are `\n`.
+fn split<'a>(text: &'a Text, structure: Structure) -> Groups<'a> {
+    let input = text.trim_end_matches('\n');
+    match structure {
+        Structure::Lines => vec![input.lines().collect()],
+        Structure::Blocks { .. } | Structure::Sections => input
+            .split("\n\n")
+            .map(|group| group.lines().collect())
+            .collect(),
+    }
+}
+
+fn render(groups: &[Vec<&str>]) -> String {
+    let groups: Vec<String> = groups.iter().map(|group| group.join("\n")).collect();
+    groups.join("\n\n") + "\n"
+}
+
+fn record_count(groups: &[Vec<&str>]) -> usize {
+    groups.len() + groups.iter().map(Vec::len).sum::<usize>()
+}
+
+/// Removes chunks of `items` while `test` still holds, halving the chunks when none can go.
+///
+/// This is the complement-only variant of Zeller's ddmin. The result is 1-minimal: removing any
+/// single item of it makes `test` fail. `test` must hold for `items` itself.
+fn ddmin<T: Clone>(mut items: Vec<T>, mut test: impl FnMut(&[T]) -> bool) -> Vec<T> {
+    let mut chunks = 2;
+
+    while items.len() >= 2 {
+        let chunk_len = (items.len() + chunks - 1) / chunks;
+
+        let removed = (0..items.len()).step_by(chunk_len).find_map(|start| {
+            let end = (start + chunk_len).min(items.len());
+            let complement: Vec<T> = items[..start]
+                .iter()
+                .chain(&items[end..])
+                .cloned()
+                .collect();
+            test(&complement).then_some(complement)
+        });
+
+        match removed {
+            Some(complement) => {
+                items = complement;
+                chunks = (chunks - 1).max(2);
+            }
+            None if chunk_len == 1 => break,
+            None => chunks = (chunks * 2).min(items.len()),
+        }
+    }
+
+    items
+}
+
+/// Removes records from `input` for as long as `reproduces` holds for what is left, which it must
+/// for `input` itself.
+///
+/// Whole blocks are removed before the lines in them, and both are retried until neither can be
+/// removed, so removing any single record of the result no longer reproduces the failure.
+pub fn reduce(
+    input: &str,
+    structure: Structure,
+    mut reproduces: impl FnMut(&str) -> bool,
+) -> String {
+    let text = Text::new(input);
+    let mut groups = split(&text, structure);
+
+    loop {
+        let count = record_count(&groups);
+
+        if let Structure::Blocks { .. } = structure {
+            groups = ddmin(groups, |groups| reproduces(&render(groups)));
+        }
+
+        if structure != (Structure::Blocks { lines: false }) {
+            for index in 0..groups.len() {
+                let lines = ddmin(groups[index].clone(), |lines| {
+                    let mut candidate = groups.clone();
+                    candidate[index] = lines.to_vec();
+                    reproduces(&render(&candidate))
+                });
+                groups[index] = lines;
+            }
+        }
+
+        if record_count(&groups) == count {
+            break;
+        }
+    }
+
+    render(&groups)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::{self, Part};
+
+    #[test]
+    fn ddmin_finds_a_one_minimal_subset() {
+        let items: Vec<u32> = (0..100).collect();
+        let mut tests = 0;
+
+        let reduced = ddmin(items, |items| {
+            tests += 1;
+            items.contains(&13) && items.contains(&71) && items.iter().sum::<u32>() >= 100
+        });
+
+        assert_eq!(reduced.len(), 3);
+        assert!(reduced.contains(&13) && reduced.contains(&71));
+        assert!(tests < 100);
+    }
+
+    #[test]
+    fn lines_are_removed() {
+        let input = "1\n2\n3\n4\n5\n6\n7\n8\n";
+
+        assert_eq!(
+            reduce(input, Structure::Lines, |input| input.contains('3')
+                && input.contains('6')),
+            "3\n6\n"
+        );
+    }
+
+    #[test]
+    fn blocks_are_removed_before_their_lines() {
+        let input = "a\nb\n\nc\nd\n\ne\nf\n";
+        let reproduces = |input: &str| input.contains('d');
+
+        assert_eq!(
+            reduce(input, Structure::Blocks { lines: true }, reproduces),
+            "d\n"
+        );
+        assert_eq!(
+            reduce(input, Structure::Blocks { lines: false }, reproduces),
+            "c\nd\n"
+        );
+    }
+
+    #[test]
+    fn inputs_are_normalized_before_splitting() {
+        let input = "a\r\nb \r\n\t\r\nc\r\nd\r\n";
+        let reproduces = |input: &str| input.contains('d');
+
+        assert_eq!(
+            reduce(input, Structure::Blocks { lines: true }, reproduces),
+            "d\n"
+        );
+        assert_eq!(
+            reduce(input, Structure::Blocks { lines: false }, reproduces),
+            "c\nd\n"
+        );
+    }
+
+    #[test]
+    fn sections_are_kept() {
+        let input = "0: 1\n1: \"a\"\n2: \"b\"\n\naa\nab\nb\n";
+
+        assert_eq!(
+            reduce(input, Structure::Sections, |input| input.contains("1:")
+                && input.contains("ab")),
+            "1: \"a\"\n\nab\n"
+        );
+        assert_eq!(
+            reduce(input, Structure::Sections, |input| input.contains("2:")
+                && input.contains("\nb")),
+            "2: \"b\"\n\nb\n"
+        );
+    }
+
+    #[test]
+    fn days_are_reduced_by_their_records() {
+        let day19 = solver::puzzle(2020, 19).unwrap();
+        let input = include_str!("y2020/day19/test_input_1.txt");
+        // Messages that only match with the looping rules
+        let loops_matter = |input: &str| {
+            let part1 = day19.solve(input, Part::One);
+            part1.is_ok() && part1 != day19.solve(input, Part::Two)
+        };
+        let reduced = reduce(input, day19.solver.structure(), loops_matter);
+
+        let (rules, messages) = reduced.split_once("\n\n").unwrap();
+        assert_eq!(messages.lines().count(), 1);
+        // Every rule is referenced, so none can go
+        assert_eq!(rules, input.split_once("\n\n").unwrap().0);
+        assert!(loops_matter(&reduced));
+    }
+}
@@ -0,0 +1,314 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::{fmt, str::FromStr};
+
+use crate::{random::Rng, reduce::Structure, text::Text, Result};
+
+/// A day's solution, split into parsing the input once and solving each part from the result.
+pub trait Solver {
+    type Input<'a>;
+    type Answer1: fmt::Display;
+    type Answer2: fmt::Display;
+
+    /// How the input splits into records, for [`reduce`](crate::reduce).
+    const STRUCTURE: Structure = Structure::Lines;
+
+    /// Parses an input normalized by [`Text::new`], so days need not handle other line endings.
+    fn parse<'a>(input: &'a Text) -> Result<Self::Input<'a>>;
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Answer1>;
+
+    /// Returns `None` for days without a second puzzle.
+    fn part2(input: &Self::Input<'_>) -> Option<Result<Self::Answer2>>;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+pub const PARTS: [Part; 2] = [Part::One, Part::Two];
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("Invalid part: {s}")),
+        }
+    }
+}
+
+/// A stage of solving a day that can be run repeatedly, such as for timing it. Returns `None` if
+/// the day has no such stage.
+pub type Stage<'a> = dyn FnMut() -> Option<Result<()>> + 'a;
+
+/// Object-safe counterpart of [`Solver`], so that all days can be stored in one registry.
+pub trait DynSolver: Sync {
+    /// Returns `Ok(None)` if the day has no such part.
+    fn solve(&self, input: &str, part: Part) -> Result<Option<String>>;
+
+    fn solve_all(&self, input: &str) -> Result<Vec<String>>;
+
+    /// Passes `"parse"`, `"part1"` and `"part2"` to `run` in turn, along with the stage, where the
+    /// parts solve an input that is parsed once beforehand.
+    fn stages(
+        &self,
+        input: &str,
+        run: &mut dyn FnMut(&'static str, &mut Stage) -> Result<()>,
+    ) -> Result<()>;
+
+    fn structure(&self) -> Structure;
+}
+
+impl<S: Solver + Sync> DynSolver for S {
+    fn solve(&self, input: &str, part: Part) -> Result<Option<String>> {
+        let text = Text::new(input);
+        let input = S::parse(&text)?;
+
+        match part {
+            Part::One => S::part1(&input).map(|answer| Some(answer.to_string())),
+            Part::Two => S::part2(&input)
+                .map(|answer| answer.map(|answer| answer.to_string()))
+                .transpose(),
+        }
+    }
+
+    fn solve_all(&self, input: &str) -> Result<Vec<String>> {
+        let text = Text::new(input);
+        let input = S::parse(&text)?;
+
+        let mut answers = vec![S::part1(&input)?.to_string()];
+        if let Some(answer) = S::part2(&input) {
+            answers.push(answer?.to_string());
+        }
+        Ok(answers)
+    }
+
+    fn stages(
+        &self,
+        input: &str,
+        run: &mut dyn FnMut(&'static str, &mut Stage) -> Result<()>,
+    ) -> Result<()> {
+        let text = Text::new(input);
+        run("parse", &mut || Some(S::parse(&text).map(drop)))?;
+
+        let input = S::parse(&text)?;
+        run("part1", &mut || Some(S::part1(&input).map(drop)))?;
+        run("part2", &mut || {
+            S::part2(&input).map(|answer| answer.map(drop))
+        })
+    }
+
+    fn structure(&self) -> Structure {
+        S::STRUCTURE
+    }
+}
+
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    pub solver: &'static dyn DynSolver,
+    /// Generates a random valid input for the day.
+    pub generate: fn(&mut Rng) -> String,
+}
+
+impl Puzzle {
+    pub fn solve(&self, input: &str, part: Part) -> Result<Option<String>> {
+        self.solver.solve(input, part)
+    }
+
+    pub fn solve_all(&self, input: &str) -> Result<Vec<String>> {
+        self.solver.solve_all(input)
+    }
+
+    pub fn stages(
+        &self,
+        input: &str,
+        run: &mut dyn FnMut(&'static str, &mut Stage) -> Result<()>,
+    ) -> Result<()> {
+        self.solver.stages(input, run)
+    }
+}
+
+macro_rules! puzzles {
+    ($($year:literal => $year_module:ident {
+        $($day:literal => $module:ident),* $(,)?
+    }),* $(,)?) => {
+        static PUZZLES: &[Puzzle] = &[
+            $($(Puzzle {
+                year: $year,
+                day: $day,
+                solver: &crate::$year_module::$module::Solution,
+                generate: crate::$year_module::$module::generate,
+            }),*),*
+        ];
+    };
+}
+
+// Years are added as sibling modules of `y2020`, in order.
+puzzles! {
+    2020 => y2020 {
+        1 => day01,
+        2 => day02,
+        3 => day03,
+        4 => day04,
+        5 => day05,
+        6 => day06,
+        7 => day07,
+        8 => day08,
+        9 => day09,
+        10 => day10,
+        11 => day11,
+        12 => day12,
+        13 => day13,
+        14 => day14,
+        15 => day15,
+        16 => day16,
+        17 => day17,
+        18 => day18,
+        19 => day19,
+        20 => day20,
+        21 => day21,
+        22 => day22,
+        23 => day23,
+        24 => day24,
+        25 => day25,
+    },
+}
+
+/// Every day of every year, ordered by year and day.
+pub fn puzzles() -> &'static [Puzzle] {
+    PUZZLES
+}
+
+pub fn puzzle(year: u16, day: u8) -> Option<&'static Puzzle> {
+    PUZZLES
+        .iter()
+        .find(|puzzle| puzzle.year == year && puzzle.day == day)
+}
+
+/// Solves the part of the day of the year, returning `None` if there is no such puzzle.
+pub fn solve(year: u16, day: u8, part: Part, input: &str) -> Option<Result<String>> {
+    puzzle(year, day)?.solve(input, part).transpose()
+}
+
+/// The most recent year, which tools default to.
+pub fn latest_year() -> u16 {
+    PUZZLES.last().expect("there are puzzles").year
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn puzzles_cover_every_day() {
+        let days: Vec<(u16, u8)> = puzzles()
+            .iter()
+            .map(|puzzle| (puzzle.year, puzzle.day))
+            .collect();
+        assert_eq!(days, (1..=25).map(|day| (2020, day)).collect::<Vec<_>>());
+        assert_eq!(latest_year(), 2020);
+    }
+
+    #[test]
+    fn puzzle_solves_either_part() {
+        let puzzle = puzzle(2020, 1).unwrap();
+        let input = include_str!("y2020/day01/test_input.txt");
+
+        assert_eq!(puzzle.solve(input, Part::One), Ok(Some("514579".into())));
+        assert_eq!(puzzle.solve(input, Part::Two), Ok(Some("241861950".into())));
+        assert_eq!(
+            puzzle.solve_all(input),
+            Ok(vec!["514579".into(), "241861950".into()])
+        );
+    }
+
+    #[test]
+    fn solve_looks_up_year_day_and_part() {
+        let input = include_str!("y2020/day01/test_input.txt");
+
+        assert_eq!(solve(2020, 1, Part::One, input), Some(Ok("514579".into())));
+        assert_eq!(solve(2020, 25, Part::Two, "5764801\n17807724"), None);
+        assert_eq!(solve(2020, 26, Part::One, input), None);
+        assert_eq!(solve(2019, 1, Part::One, input), None);
+    }
+
+    #[test]
+    fn last_day_has_no_part2() {
+        let puzzle = puzzle(2020, 25).unwrap();
+
+        assert_eq!(puzzle.solve("5764801\n17807724", Part::Two), Ok(None));
+        assert_eq!(
+            puzzle.solve_all("5764801\n17807724"),
+            Ok(vec!["14897079".into()])
+        );
+    }
+
+    #[test]
+    fn stages_cover_the_parts_there_are() {
+        let mut runs = Vec::new();
+        let result = puzzle(2020, 25)
+            .unwrap()
+            .stages("5764801\n17807724", &mut |name, stage| {
+                runs.push((name, stage(), stage()));
+                Ok(())
+            });
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(
+            runs,
+            [
+                ("parse", Some(Ok(())), Some(Ok(()))),
+                ("part1", Some(Ok(())), Some(Ok(()))),
+                ("part2", None, None),
+            ]
+        );
+
+        let result = puzzle(2020, 1)
+            .unwrap()
+            .stages("1\nx", &mut |_, stage| stage().unwrap());
+        assert!(matches!(result, Err(Error::Parse { .. })));
+    }
+
+    #[test]
+    fn generated_inputs_depend_only_on_the_seed() {
+        for puzzle in puzzles() {
+            let input = (puzzle.generate)(&mut Rng::new(1));
+            assert_eq!((puzzle.generate)(&mut Rng::new(1)), input);
+            assert!(!input.is_empty(), "{} day {}", puzzle.year, puzzle.day);
+        }
+    }
+
+    #[test]
+    fn invalid_input_is_reported() {
+        let puzzle = puzzle(2020, 1).unwrap();
+
+        assert_eq!(
+            puzzle.solve("1721\n979\nabc\n", Part::One),
+            Err(Error::parse(
+                3,
+                1,
+                "Invalid value \"abc\": invalid digit found in string"
+            ))
+        );
+    }
+}
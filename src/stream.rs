@@ -0,0 +1,119 @@
+use std::io::BufRead;
+
+use crate::{Error, Result};
+
+/// Reads an input one line at a time, reusing one buffer so that memory use does not grow with
+/// the size of the input.
+///
+/// The lines are normalized like a [`Text`](crate::text::Text), so they are the same as those of
+/// the whole input read at once.
+pub struct Lines<R> {
+    reader: R,
+    buffer: String,
+    line_number: usize,
+    // The blank lines before the line in `buffer`, which are not yet parsed
+    blank_lines: usize,
+    // Whether `buffer` holds a line that is not yet parsed
+    held: bool,
+}
+
+impl<R: BufRead> Lines<R> {
+    pub fn new(reader: R) -> Self {
+        Lines {
+            reader,
+            buffer: String::new(),
+            line_number: 0,
+            blank_lines: 0,
+            held: false,
+        }
+    }
+
+    /// Parses the next line, without its line ending and trailing whitespace, or returns `None` at
+    /// the end of the input.
+    ///
+    /// `parse` sees the line as the whole input, and the parse errors it returns are moved to the
+    /// line's position in the input.
+    pub fn parse_next<T>(&mut self, parse: impl FnOnce(&str) -> Result<T>) -> Result<Option<T>> {
+        // Blank lines are only parsed once a line follows them, so trailing ones are left out
+        while !self.held {
+            self.buffer.clear();
+            if self.reader.read_line(&mut self.buffer)? == 0 {
+                return Ok(None);
+            }
+
+            if self.line_number + self.blank_lines == 0 && self.buffer.starts_with('\u{feff}') {
+                self.buffer.drain(..'\u{feff}'.len_utf8());
+            }
+            self.buffer.truncate(self.buffer.trim_end().len());
+
+            if self.buffer.is_empty() {
+                self.blank_lines += 1;
+            } else {
+                self.held = true;
+            }
+        }
+        self.line_number += 1;
+
+        let line = if self.blank_lines > 0 {
+            self.blank_lines -= 1;
+            ""
+        } else {
+            self.held = false;
+            &self.buffer
+        };
+
+        match parse(line) {
+            Ok(value) => Ok(Some(value)),
+            Err(Error::Parse {
+                line,
+                column,
+                message,
+            }) => Err(Error::parse(self.line_number + line - 1, column, message)),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::parse_at;
+
+    fn parse_all(input: &[u8]) -> Result<Vec<u32>> {
+        let mut lines = Lines::new(input);
+        let mut values = Vec::new();
+        while let Some(value) = lines.parse_next(|line| parse_at(line, line))? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+
+    #[test]
+    fn lines_match_str_lines() {
+        assert_eq!(parse_all(b"1\n2\r\n3"), Ok(vec![1, 2, 3]));
+        assert_eq!(parse_all(b"1\n2\n"), Ok(vec![1, 2]));
+        assert_eq!(parse_all(b""), Ok(vec![]));
+        assert_eq!(parse_all(b"\xef\xbb\xbf1 \r\n2\t\n \n\r\n"), Ok(vec![1, 2]));
+    }
+
+    #[test]
+    fn errors_are_reported_at_their_line() {
+        assert_eq!(
+            parse_all(b"1\n2\nx3\n"),
+            Err(Error::parse(
+                3,
+                1,
+                "Invalid value \"x3\": invalid digit found in string"
+            ))
+        );
+        assert_eq!(
+            parse_all(b"1\n\n \n2\n"),
+            Err(Error::parse(
+                2,
+                1,
+                "Invalid value \"\": cannot parse integer from empty string"
+            ))
+        );
+        assert!(matches!(parse_all(b"1\n\xff\n"), Err(Error::Io(_))));
+    }
+}
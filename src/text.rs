@@ -0,0 +1,91 @@
+//! Puzzle inputs in one form, whatever editor they were saved with.
+
+use alloc::{borrow::Cow, string::String};
+use core::ops::Deref;
+
+/// An input with `\n` line endings and one final newline, without a byte order mark or trailing
+/// whitespace on any line. An empty input stays empty.
+///
+/// Borrows the input when it is already in this form, as inputs saved on Unix usually are.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Text<'a>(Cow<'a, str>);
+
+impl<'a> Text<'a> {
+    pub fn new(input: &'a str) -> Self {
+        let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+        let content = input.trim_end();
+        if content.is_empty() {
+            return Text(Cow::Borrowed(""));
+        }
+
+        // The line ending that follows `content`, if it is a bare `\n`
+        let with_newline = input.get(..content.len() + 1).filter(|s| s.ends_with('\n'));
+        let is_normalized =
+            !content.contains('\r') && content.lines().all(|line| line.trim_end() == line);
+
+        match with_newline {
+            Some(with_newline) if is_normalized => Text(Cow::Borrowed(with_newline)),
+            _ => {
+                let mut normalized = String::with_capacity(content.len() + 1);
+                for line in content.lines() {
+                    normalized.push_str(line.trim_end());
+                    normalized.push('\n');
+                }
+                Text(Cow::Owned(normalized))
+            }
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Deref for Text<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// `input` as it might have been saved by other editors, for testing that the days do not depend
+/// on how their input was saved.
+#[cfg(test)]
+pub(crate) fn resaved(input: &str) -> [String; 3] {
+    let windows = format!("\u{feff}{}\r\n", input.trim_end().replace('\n', "\r\n"));
+    let padded: String = input
+        .trim_end()
+        .lines()
+        .map(|line| format!("{line} \t\n"))
+        .collect();
+    let unterminated = input.trim_end().into();
+
+    [windows, padded + "\n\n", unterminated]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalized_inputs_are_borrowed() {
+        let input = "a\n\nb c\n";
+        assert!(matches!(Text::new(input).0, Cow::Borrowed(text) if text == input));
+        assert!(matches!(Text::new("a\nb\n\n\n").0, Cow::Borrowed("a\nb\n")));
+        assert!(matches!(Text::new(" \n\t").0, Cow::Borrowed("")));
+    }
+
+    #[test]
+    fn other_line_endings_and_whitespace_are_removed() {
+        let expected = "a\n\n b\n";
+        for input in resaved(expected) {
+            assert_eq!(Text::new(&input).as_str(), expected, "{input:?}");
+        }
+        assert_eq!(
+            Text::new("\u{feff}a\r\n\r\nb \r\n\r\n").as_str(),
+            "a\n\nb\n"
+        );
+        assert_eq!(&*Text::new("a \nb"), "a\nb\n");
+    }
+}
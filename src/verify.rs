@@ -0,0 +1,217 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    allocations::{self, AllocationStats},
+    error::parse_at,
+    solver::{self, Part},
+    Error, Result,
+};
+
+/// An expected answer from a manifest line `<year> <day> <part> <input> <answer>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expectation {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub input: PathBuf,
+    pub answer: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail { actual: String },
+    Error(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct Verification {
+    pub expectation: Expectation,
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+    /// Heap usage while solving, if the `count-allocations` feature is enabled.
+    pub allocations: Option<AllocationStats>,
+}
+
+fn next_field<'a>(manifest: &str, rest: &mut &'a str, name: &str) -> Result<&'a str> {
+    let trimmed = rest.trim_start();
+    if trimmed.is_empty() {
+        return Err(Error::at(manifest, trimmed, format!("Expected {name}")));
+    }
+
+    let (field, remainder) = trimmed
+        .split_once(char::is_whitespace)
+        .unwrap_or((trimmed, &trimmed[trimmed.len()..]));
+    *rest = remainder;
+    Ok(field)
+}
+
+/// Parses a manifest, resolving input paths relative to `dir`. Blank lines and lines starting
+/// with `#` are skipped.
+pub fn parse_manifest(manifest: &str, dir: &Path) -> Result<Vec<Expectation>> {
+    manifest
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .map(|line| {
+            let mut rest = line;
+
+            let year_field = next_field(manifest, &mut rest, "a year")?;
+            let year = parse_at(manifest, year_field)?;
+            if !solver::puzzles().iter().any(|puzzle| puzzle.year == year) {
+                return Err(Error::at(
+                    manifest,
+                    year_field,
+                    format!("There are no solutions for {year}"),
+                ));
+            }
+
+            let day_field = next_field(manifest, &mut rest, "a day")?;
+            let day = parse_at(manifest, day_field)?;
+            if solver::puzzle(year, day).is_none() {
+                return Err(Error::at(
+                    manifest,
+                    day_field,
+                    "Expected a day from 1 to 25",
+                ));
+            }
+
+            let part = parse_at(manifest, next_field(manifest, &mut rest, "a part")?)?;
+            let input = dir.join(next_field(manifest, &mut rest, "an input path")?);
+
+            let answer = rest.trim();
+            if answer.is_empty() {
+                return Err(Error::at(manifest, rest, "Expected an answer"));
+            }
+
+            Ok(Expectation {
+                year,
+                day,
+                part,
+                input,
+                answer: answer.into(),
+            })
+        })
+        .collect()
+}
+
+/// Solves the expected day and part for its input, and compares the answers.
+pub fn verify(expectation: Expectation) -> Verification {
+    let start = Instant::now();
+    let puzzle = solver::puzzle(expectation.year, expectation.day)
+        .expect("manifest days are validated when parsed");
+
+    let (outcome, allocations) = match fs::read_to_string(&expectation.input) {
+        Err(err) => {
+            let message = format!("Failed to read {}: {err}", expectation.input.display());
+            (Outcome::Error(message), None)
+        }
+        Ok(input) => {
+            let (answer, allocations) =
+                allocations::measure(|| puzzle.solve(&input, expectation.part));
+            let outcome = match answer {
+                Ok(Some(actual)) if actual == expectation.answer => Outcome::Pass,
+                Ok(Some(actual)) => Outcome::Fail { actual },
+                Ok(None) => Outcome::Error(format!(
+                    "Day {} has no part {}",
+                    expectation.day, expectation.part
+                )),
+                Err(err) => Outcome::Error(err.to_string()),
+            };
+            (outcome, allocations)
+        }
+    };
+
+    Verification {
+        expectation,
+        outcome,
+        elapsed: start.elapsed(),
+        allocations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = include_str!("../answers.txt");
+
+    fn manifest_dir() -> &'static Path {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+    }
+
+    #[test]
+    fn manifest_covers_every_day() {
+        let expectations = parse_manifest(MANIFEST, manifest_dir()).unwrap();
+
+        for puzzle in solver::puzzles() {
+            assert!(expectations.iter().any(
+                |expectation| (expectation.year, expectation.day) == (puzzle.year, puzzle.day)
+            ));
+        }
+        for expectation in &expectations {
+            assert!(expectation.input.is_file(), "{expectation:?}");
+        }
+    }
+
+    /// The answers for the puzzle inputs are only listed in the manifest, so this is what checks
+    /// them.
+    #[test]
+    fn manifest_answers_are_correct() {
+        for expectation in parse_manifest(MANIFEST, manifest_dir()).unwrap() {
+            let verification = verify(expectation);
+            assert_eq!(
+                verification.outcome,
+                Outcome::Pass,
+                "{:?}",
+                verification.expectation
+            );
+        }
+    }
+
+    #[test]
+    fn verify_reports_outcomes() {
+        let manifest = "\
+# Day 1
+2020 1 1 src/y2020/day01/test_input.txt 514579
+2020 1 2 src/y2020/day01/test_input.txt 1
+2020 1 2 src/y2020/day01/missing.txt    1";
+        let outcomes: Vec<Outcome> = parse_manifest(manifest, manifest_dir())
+            .unwrap()
+            .into_iter()
+            .map(|expectation| verify(expectation).outcome)
+            .collect();
+
+        assert_eq!(outcomes[0], Outcome::Pass);
+        assert_eq!(
+            outcomes[1],
+            Outcome::Fail {
+                actual: "241861950".into()
+            }
+        );
+        assert!(matches!(outcomes[2], Outcome::Error(_)));
+    }
+
+    #[test]
+    fn invalid_manifest_is_reported() {
+        assert_eq!(
+            parse_manifest("2020 1 1 input.txt 2\n2020 26 1 input.txt 2", Path::new("")),
+            Err(Error::parse(2, 6, "Expected a day from 1 to 25"))
+        );
+        assert_eq!(
+            parse_manifest("1999 1 1 input.txt 2", Path::new("")),
+            Err(Error::parse(1, 1, "There are no solutions for 1999"))
+        );
+        assert_eq!(
+            parse_manifest("2020 1 3 input.txt 2", Path::new("")),
+            Err(Error::parse(1, 8, "Invalid value \"3\": Invalid part: 3"))
+        );
+        assert_eq!(
+            parse_manifest("2020 1 1 input.txt", Path::new("")),
+            Err(Error::parse(1, 19, "Expected an answer"))
+        );
+    }
+}
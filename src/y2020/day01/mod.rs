@@ -0,0 +1,362 @@
+use alloc::{string::String, vec, vec::Vec};
+#[cfg(feature = "std")]
+use std::io::BufRead;
+
+use itertools::Itertools;
+
+#[cfg(feature = "std")]
+use crate::stream::Lines;
+use crate::{error::parse_at, random::Rng, solver::Solver, text::Text, Error, Result};
+
+const TARGET: u32 = 2020;
+
+/// Puzzle constants, defaulting to those of the actual puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    /// The sum that the entries must add up to.
+    pub target: u32,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params { target: TARGET }
+    }
+}
+
+fn parse_line(input: &str, line: &str) -> Result<Option<u32>> {
+    let line = line.trim();
+    if line.is_empty() {
+        Ok(None)
+    } else {
+        parse_at(input, line).map(Some)
+    }
+}
+
+fn parse_lines(input: &str) -> Result<Vec<u32>> {
+    input
+        .lines()
+        .filter_map(|line| parse_line(input, line).transpose())
+        .collect()
+}
+
+fn find_pair_product(nums: &[u32], target: u32) -> Result<u32> {
+    let (a, b) = nums
+        .iter()
+        .copied()
+        .tuple_combinations()
+        .find(|(a, b)| a.checked_add(*b) == Some(target))
+        .ok_or(Error::NoSolution)?;
+    a.checked_mul(b).ok_or(Error::NoSolution)
+}
+
+fn find_triple_product(nums: &[u32], target: u32) -> Result<u32> {
+    let (a, b, c) = nums
+        .iter()
+        .copied()
+        .tuple_combinations()
+        .find(|(a, b, c)| a.checked_add(*b).and_then(|sum| sum.checked_add(*c)) == Some(target))
+        .ok_or(Error::NoSolution)?;
+    product(&[a, b, c])
+}
+
+/// Returns the product of `nums`, or [`Error::NoSolution`] if it does not fit in a `u32`.
+fn product(nums: &[u32]) -> Result<u32> {
+    nums.iter()
+        .try_fold(1u32, |product, num| product.checked_mul(*num))
+        .ok_or(Error::NoSolution)
+}
+
+pub fn part1(input: &str) -> Result<u32> {
+    part1_with(input, &Params::default())
+}
+
+pub fn part2(input: &str) -> Result<u32> {
+    part2_with(input, &Params::default())
+}
+
+pub fn part1_with(input: &str, params: &Params) -> Result<u32> {
+    find_pair_product(&Solution::parse(&Text::new(input))?, params.target)
+}
+
+pub fn part2_with(input: &str, params: &Params) -> Result<u32> {
+    find_triple_product(&Solution::parse(&Text::new(input))?, params.target)
+}
+
+/// Reads the first three positions of every entry that could be part of a sum to `target`.
+#[cfg(feature = "std")]
+fn read_first_positions(reader: impl BufRead, target: u32) -> Result<Vec<Vec<usize>>> {
+    let mut first_positions = vec![Vec::new(); target as usize + 1];
+    let mut lines = Lines::new(reader);
+    let mut position = 0;
+
+    while let Some(num) = lines.parse_next(|line| parse_line(line, line))? {
+        if let Some(num) = num {
+            if let Some(positions) = first_positions.get_mut(num as usize) {
+                if positions.len() < 3 {
+                    positions.push(position);
+                }
+            }
+            position += 1;
+        }
+    }
+
+    Ok(first_positions)
+}
+
+/// Returns the product of the earliest entries that sum to the target, which is the same
+/// combination that [`find_pair_product`] and [`find_triple_product`] find first.
+#[cfg(feature = "std")]
+fn earliest_product<const N: usize>(
+    first_positions: &[Vec<usize>],
+    sums: impl Iterator<Item = [u32; N]>,
+) -> Result<u32> {
+    sums.filter_map(|nums| {
+        let mut positions = [0; N];
+        for (index, num) in nums.iter().enumerate() {
+            let earlier_count = nums[..index].iter().filter(|n| *n == num).count();
+            positions[index] = *first_positions[*num as usize].get(earlier_count)?;
+        }
+        positions.sort_unstable();
+        Some((positions, nums))
+    })
+    .min()
+    .ok_or(Error::NoSolution)
+    .and_then(|(_, nums)| product(&nums))
+}
+
+/// Like [`part1`], but reads the input line by line, keeping only the first positions of each
+/// entry from 0 to 2020.
+#[cfg(feature = "std")]
+pub fn part1_from_reader(reader: impl BufRead) -> Result<u32> {
+    part1_from_reader_with(reader, &Params::default())
+}
+
+/// Like [`part2`], but reads the input line by line, keeping only the first positions of each
+/// entry from 0 to 2020.
+#[cfg(feature = "std")]
+pub fn part2_from_reader(reader: impl BufRead) -> Result<u32> {
+    part2_from_reader_with(reader, &Params::default())
+}
+
+/// Like [`part1_with`], but reads the input line by line, keeping only the first positions of
+/// each entry up to the target.
+#[cfg(feature = "std")]
+pub fn part1_from_reader_with(reader: impl BufRead, params: &Params) -> Result<u32> {
+    let target = params.target;
+    let first_positions = read_first_positions(reader, target)?;
+    earliest_product(&first_positions, (0..=target / 2).map(|a| [a, target - a]))
+}
+
+/// Like [`part2_with`], but reads the input line by line, keeping only the first positions of
+/// each entry up to the target.
+#[cfg(feature = "std")]
+pub fn part2_from_reader_with(reader: impl BufRead, params: &Params) -> Result<u32> {
+    let target = params.target;
+    let first_positions = read_first_positions(reader, target)?;
+    let sums =
+        (0..=target / 3).flat_map(|a| (a..=(target - a) / 2).map(move |b| [a, b, target - a - b]));
+    earliest_product(&first_positions, sums)
+}
+
+/// Generates entries with exactly one pair and one triple that sum to 2020.
+pub fn generate(rng: &mut Rng) -> String {
+    let pair_count = |nums: &[u32]| {
+        nums.iter()
+            .tuple_combinations()
+            .filter(|(a, b)| *a + *b == TARGET)
+            .count()
+    };
+    let triple_count = |nums: &[u32]| {
+        nums.iter()
+            .tuple_combinations()
+            .filter(|(a, b, c)| *a + *b + *c == TARGET)
+            .count()
+    };
+
+    let mut nums = loop {
+        let a = rng.range(1..TARGET as usize) as u32;
+        let x = rng.range(1..TARGET as usize - 1) as u32;
+        let y = rng.range(1..(TARGET - x) as usize) as u32;
+        let nums = vec![a, TARGET - a, x, y, TARGET - x - y];
+
+        if pair_count(&nums) == 1 && triple_count(&nums) == 1 {
+            break nums;
+        }
+    };
+
+    for _ in 0..rng.range(0..100) {
+        let num = rng.range(1..TARGET as usize) as u32;
+        let completes_sum = nums.iter().any(|a| a + num == TARGET)
+            || nums
+                .iter()
+                .tuple_combinations()
+                .any(|(a, b)| a + b + num == TARGET);
+
+        if !completes_sum {
+            nums.push(num);
+        }
+    }
+
+    rng.shuffle(&mut nums);
+    nums.iter().join("\n")
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Vec<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse<'a>(input: &'a Text) -> Result<Self::Input<'a>> {
+        parse_lines(input)
+    }
+
+    fn part1(nums: &Self::Input<'_>) -> Result<Self::Answer1> {
+        find_pair_product(nums, TARGET)
+    }
+
+    fn part2(nums: &Self::Input<'_>) -> Option<Result<Self::Answer2>> {
+        Some(find_triple_product(nums, TARGET))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::resaved;
+
+    static TEST_INPUT: &str = include_str!("test_input.txt");
+    #[cfg(feature = "std")]
+    static INPUT: &str = include_str!("input.txt");
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(part1(TEST_INPUT), Ok(514_579));
+    }
+
+    #[test]
+    fn part2_works() {
+        assert_eq!(part2(TEST_INPUT), Ok(241_861_950));
+    }
+
+    #[test]
+    fn resaved_input_works() {
+        for input in &resaved(TEST_INPUT) {
+            assert_eq!(part1(input), part1(TEST_INPUT));
+            assert_eq!(part2(input), part2(TEST_INPUT));
+        }
+    }
+
+    #[test]
+    fn params_change_the_target() {
+        let params = Params { target: 1345 };
+        assert_eq!(part1_with(TEST_INPUT, &params), Ok(979 * 366));
+        let params = Params { target: 1644 };
+        assert_eq!(part2_with(TEST_INPUT, &params), Ok(979 * 366 * 299));
+    }
+
+    #[test]
+    fn invalid_input_is_reported() {
+        assert_eq!(
+            part1("1721\n97x\n"),
+            Err(Error::parse(
+                2,
+                1,
+                "Invalid value \"97x\": invalid digit found in string"
+            ))
+        );
+        assert_eq!(part1("1\n2\n"), Err(Error::NoSolution));
+    }
+
+    #[test]
+    fn overflows_give_no_solution() {
+        let input = "4294967295\n4294967295\n2020\n0\n";
+        assert_eq!(part1(input), Ok(0));
+        assert_eq!(part2(input), Err(Error::NoSolution));
+
+        let params = Params { target: 4294967295 };
+        assert_eq!(
+            part1_with("65536\n4294901759\n", &params),
+            Err(Error::NoSolution)
+        );
+        assert_eq!(
+            part2_with("65536\n4294901758\n1\n", &params),
+            Err(Error::NoSolution)
+        );
+    }
+
+    fn reference_product(input: &str, count: usize) -> u32 {
+        let nums: Vec<u32> = input.lines().map(|line| line.parse().unwrap()).collect();
+        let mut products = Vec::new();
+
+        for i in 0..nums.len() {
+            for j in i + 1..nums.len() {
+                if count == 2 && nums[i] + nums[j] == 2020 {
+                    products.push(nums[i] * nums[j]);
+                }
+                for k in j + 1..nums.len() {
+                    if count == 3 && nums[i] + nums[j] + nums[k] == 2020 {
+                        products.push(nums[i] * nums[j] * nums[k]);
+                    }
+                }
+            }
+        }
+
+        assert_eq!(products.len(), 1);
+        products[0]
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reader_gives_same_results() {
+        for input in &resaved(TEST_INPUT) {
+            assert_eq!(part1_from_reader(input.as_bytes()), part1(input));
+            assert_eq!(part2_from_reader(input.as_bytes()), part2(input));
+        }
+        let inputs = [TEST_INPUT, INPUT, "1010\n5\n1010\n1\n1009\n1009\n2\n"];
+
+        for input in inputs {
+            assert_eq!(part1_from_reader(input.as_bytes()), part1(input));
+            assert_eq!(part2_from_reader(input.as_bytes()), part2(input));
+        }
+        for seed in 0..20 {
+            let input = generate(&mut Rng::new(seed));
+            assert_eq!(part1_from_reader(input.as_bytes()), part1(&input));
+            assert_eq!(part2_from_reader(input.as_bytes()), part2(&input));
+        }
+        for target in [1345, 1644, 4000] {
+            let params = Params { target };
+            assert_eq!(
+                part1_from_reader_with(TEST_INPUT.as_bytes(), &params),
+                part1_with(TEST_INPUT, &params)
+            );
+            assert_eq!(
+                part2_from_reader_with(TEST_INPUT.as_bytes(), &params),
+                part2_with(TEST_INPUT, &params)
+            );
+        }
+
+        assert_eq!(
+            part1_from_reader("1721\n979\nabc\n".as_bytes()),
+            part1("1721\n979\nabc\n")
+        );
+    }
+
+    #[test]
+    fn random_inputs_match_reference() {
+        for seed in 0..100 {
+            let input = generate(&mut Rng::new(seed));
+            assert_eq!(
+                part1(&input),
+                Ok(reference_product(&input, 2)),
+                "seed {seed}"
+            );
+            assert_eq!(
+                part2(&input),
+                Ok(reference_product(&input, 3)),
+                "seed {seed}"
+            );
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use alloc::{format, string::String, vec::Vec};
+#[cfg(feature = "std")]
+use std::io::BufRead;
+
+use itertools::Itertools;
+
+#[cfg(feature = "std")]
+use crate::stream::Lines;
+use crate::{parser::Parser, random::Rng, solver::Solver, text::Text, Result};
+
+#[derive(Debug)]
+pub struct Entry<'a> {
+    a: usize,
+    b: usize,
+    letter: char,
+    password: &'a str,
+}
+
+fn parse_entry<'a>(input: &'a str, line: &'a str) -> Result<Entry<'a>> {
+    let mut p = Parser::within(input, line);
+
+    let a = p.number()?;
+    p.tag("-")?;
+    let b = p.number()?;
+    p.tag(" ")?;
+    let letter = p.char()?;
+    p.tag(": ")?;
+    let password = p.word()?;
+    p.end()?;
+
+    Ok(Entry {
+        a,
+        b,
+        letter,
+        password,
+    })
+}
+
+fn parse_entries(input: &str) -> Result<Vec<Entry<'_>>> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| parse_entry(input, line))
+        .collect()
+}
+
+fn is_valid_by_count(entry: &Entry) -> bool {
+    let count = entry
+        .password
+        .chars()
+        .filter(|char| *char == entry.letter)
+        .count();
+
+    count >= entry.a && count <= entry.b
+}
+
+fn is_valid_by_position(entry: &Entry) -> bool {
+    let letter_at = |position: usize| {
+        let index = position.checked_sub(1)?;
+        entry.password.chars().nth(index)
+    };
+
+    let match_a = letter_at(entry.a) == Some(entry.letter);
+    let match_b = letter_at(entry.b) == Some(entry.letter);
+
+    match_a && !match_b || !match_a && match_b
+}
+
+fn count_valid_by_count(entries: &[Entry]) -> usize {
+    entries
+        .iter()
+        .filter(|entry| is_valid_by_count(entry))
+        .count()
+}
+
+fn count_valid_by_position(entries: &[Entry]) -> usize {
+    entries
+        .iter()
+        .filter(|entry| is_valid_by_position(entry))
+        .count()
+}
+
+/// Counts the valid entries while reading the input line by line.
+#[cfg(feature = "std")]
+fn count_valid_in(reader: impl BufRead, is_valid: fn(&Entry) -> bool) -> Result<usize> {
+    let mut lines = Lines::new(reader);
+    let mut count = 0;
+
+    while let Some(valid) =
+        lines.parse_next(|line| Ok(!line.is_empty() && is_valid(&parse_entry(line, line)?)))?
+    {
+        count += usize::from(valid);
+    }
+
+    Ok(count)
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(count_valid_by_count(&Solution::parse(&Text::new(input))?))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(count_valid_by_position(&Solution::parse(&Text::new(
+        input,
+    ))?))
+}
+
+/// Like [`part1`], but reads the input line by line.
+#[cfg(feature = "std")]
+pub fn part1_from_reader(reader: impl BufRead) -> Result<usize> {
+    count_valid_in(reader, is_valid_by_count)
+}
+
+/// Like [`part2`], but reads the input line by line.
+#[cfg(feature = "std")]
+pub fn part2_from_reader(reader: impl BufRead) -> Result<usize> {
+    count_valid_in(reader, is_valid_by_position)
+}
+
+/// Generates entries over a small alphabet, so that policies are often met.
+pub fn generate(rng: &mut Rng) -> String {
+    let alphabet = ['a', 'b', 'c', 'd', 'e'];
+
+    (0..rng.range(1..200))
+        .map(|_| {
+            let a = rng.range(1..8);
+            let b = rng.range(a..a + 8);
+            let letter = rng.choose(&alphabet);
+            let password: String = (0..rng.range(1..16))
+                .map(|_| *rng.choose(&alphabet))
+                .collect();
+
+            format!("{a}-{b} {letter}: {password}")
+        })
+        .join("\n")
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Vec<Entry<'a>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<'a>(input: &'a Text) -> Result<Self::Input<'a>> {
+        parse_entries(input)
+    }
+
+    fn part1(entries: &Self::Input<'_>) -> Result<Self::Answer1> {
+        Ok(count_valid_by_count(entries))
+    }
+
+    fn part2(entries: &Self::Input<'_>) -> Option<Result<Self::Answer2>> {
+        Some(Ok(count_valid_by_position(entries)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::resaved;
+    use crate::Error;
+
+    static TEST_INPUT: &str = include_str!("test_input.txt");
+    #[cfg(feature = "std")]
+    static INPUT: &str = include_str!("input.txt");
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(part1(TEST_INPUT), Ok(2));
+    }
+
+    #[test]
+    fn part2_works() {
+        assert_eq!(part2(TEST_INPUT), Ok(1));
+    }
+
+    #[test]
+    fn resaved_input_works() {
+        for input in &resaved(TEST_INPUT) {
+            assert_eq!(part1(input), part1(TEST_INPUT));
+            assert_eq!(part2(input), part2(TEST_INPUT));
+        }
+    }
+
+    #[test]
+    fn invalid_input_is_reported() {
+        assert_eq!(
+            part1("1-3 a: abcde\n1-3 b cdefg\n"),
+            Err(Error::parse(2, 6, "Expected `: `"))
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reader_gives_same_results() {
+        for input in &resaved(TEST_INPUT) {
+            assert_eq!(part1_from_reader(input.as_bytes()), part1(input));
+            assert_eq!(part2_from_reader(input.as_bytes()), part2(input));
+        }
+        let invalid_input = "1-3 a: abcde\n1-3 b cdefg\n";
+
+        for input in [TEST_INPUT, INPUT, invalid_input] {
+            assert_eq!(part1_from_reader(input.as_bytes()), part1(input));
+            assert_eq!(part2_from_reader(input.as_bytes()), part2(input));
+        }
+        for seed in 0..20 {
+            let input = generate(&mut Rng::new(seed));
+            assert_eq!(part1_from_reader(input.as_bytes()), part1(&input));
+            assert_eq!(part2_from_reader(input.as_bytes()), part2(&input));
+        }
+    }
+
+    fn reference_counts(input: &str) -> (usize, usize) {
+        let mut counts = (0, 0);
+
+        for line in input.lines() {
+            let (policy, password) = line.split_once(": ").unwrap();
+            let (range, letter) = policy.split_once(' ').unwrap();
+            let (a, b) = range.split_once('-').unwrap();
+            let (a, b): (usize, usize) = (a.parse().unwrap(), b.parse().unwrap());
+            let letter = letter.as_bytes()[0];
+            let password = password.as_bytes();
+
+            let count = password.iter().filter(|c| **c == letter).count();
+            if a <= count && count <= b {
+                counts.0 += 1;
+            }
+            if (password.get(a - 1) == Some(&letter)) != (password.get(b - 1) == Some(&letter)) {
+                counts.1 += 1;
+            }
+        }
+
+        counts
+    }
+
+    #[test]
+    fn random_inputs_match_reference() {
+        for seed in 0..100 {
+            let input = generate(&mut Rng::new(seed));
+            let (expected1, expected2) = reference_counts(&input);
+            assert_eq!(part1(&input), Ok(expected1), "seed {seed}");
+            assert_eq!(part2(&input), Ok(expected2), "seed {seed}");
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    grid::{Grid, Wrap},
+    random::Rng,
+    solver::Solver,
+    text::Text,
+    Result,
+};
+
+type Forest = Grid<bool>;
+
+/// A slope, as the number of steps right and down.
+pub type Slope = (usize, usize);
+
+/// Puzzle constants, defaulting to those of the actual puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    /// The slope to check in part 1.
+    pub slope: Slope,
+    /// The slopes whose tree counts are multiplied in part 2.
+    pub slopes: Vec<Slope>,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            slope: (3, 1),
+            slopes: vec![(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)],
+        }
+    }
+}
+
+fn parse_forest(input: &str) -> Result<Forest> {
+    Grid::parse(input, "`.` or `#`", |c| match c {
+        '.' => Some(false),
+        '#' => Some(true),
+        _ => None,
+    })
+}
+
+fn count_trees(forest: &Forest, (right, down): Slope) -> usize {
+    let start = (0, 0);
+    let trees = forest
+        .ray(start, (right as isize, down as isize), Wrap::Horizontal)
+        .filter(|(_, tree)| **tree)
+        .count();
+
+    trees + forest.get(start).map_or(0, |tree| *tree as usize)
+}
+
+fn count_trees_on_slopes(forest: &Forest, slopes: &[Slope]) -> usize {
+    slopes
+        .iter()
+        .map(|slope| count_trees(forest, *slope))
+        .product()
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    part1_with(input, &Params::default())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    part2_with(input, &Params::default())
+}
+
+pub fn part1_with(input: &str, params: &Params) -> Result<usize> {
+    Ok(count_trees(
+        &Solution::parse(&Text::new(input))?,
+        params.slope,
+    ))
+}
+
+pub fn part2_with(input: &str, params: &Params) -> Result<usize> {
+    Ok(count_trees_on_slopes(
+        &Solution::parse(&Text::new(input))?,
+        &params.slopes,
+    ))
+}
+
+/// Generates a forest of random size and density.
+pub fn generate(rng: &mut Rng) -> String {
+    let width = rng.range(1..40);
+    let height = rng.range(1..80);
+    let density = rng.range(5..50) as f64 / 100.0;
+
+    Grid::from_fn(
+        width,
+        height,
+        |_| if rng.chance(density) { '#' } else { '.' },
+    )
+    .to_string()
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Forest;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<'a>(input: &'a Text) -> Result<Self::Input<'a>> {
+        parse_forest(input)
+    }
+
+    fn part1(forest: &Self::Input<'_>) -> Result<Self::Answer1> {
+        Ok(count_trees(forest, Params::default().slope))
+    }
+
+    fn part2(forest: &Self::Input<'_>) -> Option<Result<Self::Answer2>> {
+        Some(Ok(count_trees_on_slopes(forest, &Params::default().slopes)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::resaved;
+
+    static TEST_INPUT: &str = include_str!("test_input.txt");
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(part1(TEST_INPUT), Ok(7));
+    }
+
+    #[test]
+    fn part2_works() {
+        assert_eq!(part2(TEST_INPUT), Ok(336));
+    }
+
+    #[test]
+    fn resaved_input_works() {
+        for input in &resaved(TEST_INPUT) {
+            assert_eq!(part1(input), part1(TEST_INPUT));
+            assert_eq!(part2(input), part2(TEST_INPUT));
+        }
+    }
+
+    #[test]
+    fn params_change_the_slopes() {
+        let params = Params {
+            slope: (1, 2),
+            slopes: vec![(1, 1), (5, 1)],
+        };
+        assert_eq!(part1_with(TEST_INPUT, &params), Ok(2));
+        assert_eq!(part2_with(TEST_INPUT, &params), Ok(2 * 3));
+    }
+
+    fn reference_trees(input: &str, right: usize, down: usize) -> usize {
+        input
+            .lines()
+            .step_by(down)
+            .enumerate()
+            .filter(|(step, line)| line.as_bytes()[step * right % line.len()] == b'#')
+            .count()
+    }
+
+    #[test]
+    fn random_inputs_match_reference() {
+        for seed in 0..100 {
+            let input = generate(&mut Rng::new(seed));
+            let slopes = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+            let product = slopes
+                .iter()
+                .map(|(right, down)| reference_trees(&input, *right, *down))
+                .product();
+
+            assert_eq!(
+                part1(&input),
+                Ok(reference_trees(&input, 3, 1)),
+                "seed {seed}"
+            );
+            assert_eq!(part2(&input), Ok(product), "seed {seed}");
+        }
+    }
+}
@@ -0,0 +1,302 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{
+    collections::HashMap, parser::Parser, random::Rng, reduce::Structure, solver::Solver,
+    text::Text, Result,
+};
+
+type Passport<'a> = HashMap<&'a str, &'a str>;
+
+fn parse_field<'a>(input: &'a str, field: &'a str) -> Result<(&'a str, &'a str)> {
+    let mut p = Parser::within(input, field);
+
+    let key = p.word()?;
+    p.tag(":")?;
+    let value = p.rest();
+
+    Ok((key, value))
+}
+
+fn parse_passports(input: &str) -> Result<Vec<Passport<'_>>> {
+    Parser::new(input)
+        .blocks()
+        .map(|block| {
+            block
+                .rest()
+                .split_whitespace()
+                .map(|field| parse_field(input, field))
+                .collect()
+        })
+        .collect()
+}
+
+fn is_valid_height(value: &str) -> bool {
+    let mut p = Parser::new(value);
+    let height = p.take_while(|c| c.is_ascii_digit()).parse::<u32>();
+
+    let range = if p.eat("cm") {
+        150..=193
+    } else if p.eat("in") {
+        59..=76
+    } else {
+        return false;
+    };
+
+    p.is_empty() && height.map_or(false, |height| range.contains(&height))
+}
+
+fn is_valid_hair_color(value: &str) -> bool {
+    value.strip_prefix('#').map_or(false, |color| {
+        color.len() == 6 && color.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f'))
+    })
+}
+
+fn is_valid_passport_id(value: &str) -> bool {
+    value.len() == 9 && value.chars().all(|c| c.is_ascii_digit())
+}
+
+const VALID_FIELDS: [&str; 7] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+
+const VALID_EYE_COLORS: [&str; 7] = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+
+fn count_complete_passports(passports: &[Passport]) -> usize {
+    passports
+        .iter()
+        .filter(|passport| {
+            VALID_FIELDS
+                .iter()
+                .all(|field| passport.get(*field).is_some())
+        })
+        .count()
+}
+
+fn count_valid_passports(passports: &[Passport]) -> usize {
+    passports
+        .iter()
+        .filter(|passport| {
+            VALID_FIELDS.into_iter().all(|field| {
+                if let Some(value) = passport.get(field) {
+                    match field {
+                        "byr" => value
+                            .parse()
+                            .map_or(false, |num_value| (1920..=2002).contains(&num_value)),
+                        "iyr" => value
+                            .parse()
+                            .map_or(false, |num_value| (2010..=2020).contains(&num_value)),
+                        "eyr" => value
+                            .parse()
+                            .map_or(false, |num_value| (2020..=2030).contains(&num_value)),
+                        "hgt" => is_valid_height(value),
+                        "hcl" => is_valid_hair_color(value),
+                        "ecl" => VALID_EYE_COLORS.contains(value),
+                        "pid" => is_valid_passport_id(value),
+                        _ => panic!("Invalid field: {field}"),
+                    }
+                } else {
+                    false
+                }
+            })
+        })
+        .count()
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(count_complete_passports(&Solution::parse(&Text::new(
+        input,
+    ))?))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(count_valid_passports(&Solution::parse(&Text::new(input))?))
+}
+
+fn random_digits(rng: &mut Rng, count: usize) -> String {
+    (0..count)
+        .map(|_| char::from(b'0' + rng.range(0..10) as u8))
+        .collect()
+}
+
+fn random_field_value(rng: &mut Rng, field: &str) -> String {
+    match field {
+        "byr" => rng.range(1900..2020).to_string(),
+        "iyr" => rng.range(2000..2030).to_string(),
+        "eyr" => rng.range(2010..2040).to_string(),
+        "hgt" => format!(
+            "{}{}",
+            rng.range(50..200),
+            rng.choose(&["cm", "in", "", "mm"])
+        ),
+        "hcl" => {
+            let prefix = if rng.chance(0.9) { "#" } else { "" };
+            let color: String = (0..rng.range(5..8))
+                .map(|_| *rng.choose(b"0123456789abcdefz") as char)
+                .collect();
+            format!("{prefix}{color}")
+        }
+        "ecl" => {
+            let colors = [&VALID_EYE_COLORS[..], &["xyz", "gmt", "zzz"]].concat();
+            rng.choose(&colors).to_string()
+        }
+        "pid" => {
+            let count = rng.range(8..11);
+            random_digits(rng, count)
+        }
+        _ => random_digits(rng, 3),
+    }
+}
+
+/// Generates passports with missing fields and with values on both sides of each bound.
+pub fn generate(rng: &mut Rng) -> String {
+    let mut passports = Vec::new();
+
+    for _ in 0..rng.range(1..50) {
+        let mut fields = Vec::new();
+        for field in VALID_FIELDS.iter().chain(&["cid"]) {
+            if rng.chance(0.9) {
+                fields.push(format!("{field}:{}", random_field_value(rng, field)));
+            }
+        }
+        rng.shuffle(&mut fields);
+
+        let mut passport = String::new();
+        for field in fields {
+            if !passport.is_empty() {
+                passport.push(if rng.chance(0.3) { '\n' } else { ' ' });
+            }
+            passport.push_str(&field);
+        }
+        passports.push(passport);
+    }
+
+    passports.join("\n\n")
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Vec<Passport<'a>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const STRUCTURE: Structure = Structure::Blocks { lines: true };
+
+    fn parse<'a>(input: &'a Text) -> Result<Self::Input<'a>> {
+        parse_passports(input)
+    }
+
+    fn part1(passports: &Self::Input<'_>) -> Result<Self::Answer1> {
+        Ok(count_complete_passports(passports))
+    }
+
+    fn part2(passports: &Self::Input<'_>) -> Option<Result<Self::Answer2>> {
+        Some(Ok(count_valid_passports(passports)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::resaved;
+    use crate::Error;
+
+    static TEST_INPUT: &str = include_str!("test_input.txt");
+    static TEST_INPUT_2: &str = include_str!("test_input_2.txt");
+    static TEST_INPUT_3: &str = include_str!("test_input_3.txt");
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(part1(TEST_INPUT), Ok(2));
+    }
+
+    #[test]
+    fn part2_works() {
+        assert_eq!(part2(TEST_INPUT_2), Ok(0));
+        assert_eq!(part2(TEST_INPUT_3), Ok(4));
+    }
+
+    #[test]
+    fn resaved_input_works() {
+        for input in &resaved(TEST_INPUT) {
+            assert_eq!(part1(input), part1(TEST_INPUT));
+            assert_eq!(part2(input), part2(TEST_INPUT));
+        }
+    }
+
+    #[test]
+    fn invalid_input_is_reported() {
+        assert_eq!(
+            part1("ecl:gry pid:860033327\n\nhcl #fffffd"),
+            Err(Error::parse(3, 4, "Expected `:`"))
+        );
+    }
+
+    fn reference_counts(input: &str) -> (usize, usize) {
+        let in_range = |value: &str, min: u32, max: u32| {
+            value.len() == 4 && value.parse().map_or(false, |n: u32| min <= n && n <= max)
+        };
+        let all_digits = |value: &str| value.bytes().all(|b| b.is_ascii_digit());
+
+        let mut counts = (0, 0);
+
+        for passport in input.split("\n\n") {
+            let fields: Vec<(&str, &str)> = passport
+                .split_whitespace()
+                .map(|field| field.split_once(':').unwrap())
+                .filter(|(key, _)| *key != "cid")
+                .collect();
+
+            if fields.len() < 7 {
+                continue;
+            }
+            counts.0 += 1;
+
+            let valid = fields.iter().all(|(key, value)| match *key {
+                "byr" => in_range(value, 1920, 2002),
+                "iyr" => in_range(value, 2010, 2020),
+                "eyr" => in_range(value, 2020, 2030),
+                "hgt" => {
+                    let (number, unit) = value.split_at(value.len().saturating_sub(2));
+                    let height = if all_digits(number) {
+                        number.parse().unwrap_or(0)
+                    } else {
+                        0
+                    };
+                    unit == "cm" && (150..=193).contains(&height)
+                        || unit == "in" && (59..=76).contains(&height)
+                }
+                "hcl" => {
+                    value.len() == 7
+                        && value.starts_with('#')
+                        && value[1..]
+                            .bytes()
+                            .all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+                }
+                "ecl" => "amb blu brn gry grn hzl oth"
+                    .split(' ')
+                    .any(|c| c == *value),
+                "pid" => value.len() == 9 && all_digits(value),
+                _ => unreachable!(),
+            });
+
+            if valid {
+                counts.1 += 1;
+            }
+        }
+
+        counts
+    }
+
+    #[test]
+    fn random_inputs_match_reference() {
+        for seed in 0..100 {
+            let input = generate(&mut Rng::new(seed));
+            let (expected1, expected2) = reference_counts(&input);
+            assert_eq!(part1(&input), Ok(expected1), "seed {seed}");
+            assert_eq!(part2(&input), Ok(expected2), "seed {seed}");
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use alloc::{string::String, vec::Vec};
+
+use itertools::Itertools;
+#[cfg(feature = "std")]
+use std::io::BufRead;
+
+#[cfg(feature = "std")]
+use crate::stream::Lines;
+use crate::{random::Rng, solver::Solver, text::Text, Error, Result};
+
+type BoardingPass = (usize, usize);
+
+const SEAT_COUNT: usize = 128 * 8;
+
+fn boarding_pass_id((row, column): BoardingPass) -> usize {
+    row * 8 + column
+}
+
+fn boarding_pass(input: &str) -> BoardingPass {
+    let mut row_from = 0;
+    let mut row_to = 127;
+
+    let mut column_from = 0;
+    let mut column_to = 7;
+
+    for c in input.chars() {
+        match c {
+            'B' => row_from = (row_to + row_from + 1) / 2,
+            'F' => row_to = (row_to + row_from) / 2,
+            'R' => column_from = (column_from + column_to + 1) / 2,
+            'L' => column_to = (column_from + column_to) / 2,
+            _ => {}
+        }
+    }
+
+    (row_to, column_to)
+}
+
+fn parse_boarding_pass(input: &str, line: &str) -> Result<BoardingPass> {
+    if let Some(index) = line.find(|c| !matches!(c, 'B' | 'F' | 'R' | 'L')) {
+        Err(Error::at(input, &line[index..], "Expected one of `BFRL`"))
+    } else {
+        Ok(boarding_pass(line))
+    }
+}
+
+fn parse_boarding_passes(input: &str) -> Result<Vec<BoardingPass>> {
+    input
+        .lines()
+        .map(|line| parse_boarding_pass(input, line))
+        .collect()
+}
+
+fn highest_boarding_pass_id(boarding_passes: &[BoardingPass]) -> Result<usize> {
+    boarding_passes
+        .iter()
+        .copied()
+        .map(boarding_pass_id)
+        .max()
+        .ok_or(Error::NoSolution)
+}
+
+fn find_missing_seat_id(taken: &[bool; SEAT_COUNT]) -> Result<usize> {
+    let first_row = taken
+        .iter()
+        .position(|taken| *taken)
+        .ok_or(Error::NoSolution)?
+        / 8;
+    let last_row = taken
+        .iter()
+        .rposition(|taken| *taken)
+        .ok_or(Error::NoSolution)?
+        / 8;
+
+    for id in (first_row + 1) * 8..last_row * 8 {
+        if !taken[id] && (taken[id - 1] || taken[id + 1]) {
+            return Ok(id);
+        }
+    }
+
+    Err(Error::NoSolution)
+}
+
+fn find_missing_boarding_pass_id(boarding_passes: &[BoardingPass]) -> Result<usize> {
+    let mut taken = [false; SEAT_COUNT];
+    for pass in boarding_passes {
+        taken[boarding_pass_id(*pass)] = true;
+    }
+
+    find_missing_seat_id(&taken)
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    highest_boarding_pass_id(&Solution::parse(&Text::new(input))?)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    find_missing_boarding_pass_id(&Solution::parse(&Text::new(input))?)
+}
+
+/// Like [`part1`], but reads the input line by line.
+#[cfg(feature = "std")]
+pub fn part1_from_reader(reader: impl BufRead) -> Result<usize> {
+    let mut lines = Lines::new(reader);
+    let mut highest_id = None;
+
+    while let Some(pass) = lines.parse_next(|line| parse_boarding_pass(line, line))? {
+        highest_id = highest_id.max(Some(boarding_pass_id(pass)));
+    }
+
+    highest_id.ok_or(Error::NoSolution)
+}
+
+/// Like [`part2`], but reads the input line by line, keeping only which seats are taken.
+#[cfg(feature = "std")]
+pub fn part2_from_reader(reader: impl BufRead) -> Result<usize> {
+    let mut lines = Lines::new(reader);
+    let mut taken = [false; SEAT_COUNT];
+
+    while let Some(pass) = lines.parse_next(|line| parse_boarding_pass(line, line))? {
+        taken[boarding_pass_id(pass)] = true;
+    }
+
+    find_missing_seat_id(&taken)
+}
+
+fn encode_boarding_pass(id: usize) -> String {
+    (0..10)
+        .rev()
+        .map(|bit| match (bit >= 3, id >> bit & 1 == 1) {
+            (true, false) => 'F',
+            (true, true) => 'B',
+            (false, false) => 'L',
+            (false, true) => 'R',
+        })
+        .collect()
+}
+
+/// Generates a full range of seats, with one missing seat that is not in the first or last row.
+pub fn generate(rng: &mut Rng) -> String {
+    let first_id = rng.range(0..500);
+    let last_id = first_id + rng.range(24..1024 - first_id);
+    let missing_id = rng.range((first_id / 8 + 1) * 8..last_id / 8 * 8);
+
+    let mut ids: Vec<usize> = (first_id..=last_id)
+        .filter(|id| *id != missing_id)
+        .collect();
+    rng.shuffle(&mut ids);
+
+    ids.into_iter().map(encode_boarding_pass).join("\n")
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Vec<BoardingPass>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<'a>(input: &'a Text) -> Result<Self::Input<'a>> {
+        parse_boarding_passes(input)
+    }
+
+    fn part1(boarding_passes: &Self::Input<'_>) -> Result<Self::Answer1> {
+        highest_boarding_pass_id(boarding_passes)
+    }
+
+    fn part2(boarding_passes: &Self::Input<'_>) -> Option<Result<Self::Answer2>> {
+        Some(find_missing_boarding_pass_id(boarding_passes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::resaved;
+
+    static INPUT: &str = include_str!("input.txt");
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(boarding_pass_id(boarding_pass("FBFBBFFRLR")), 357);
+        assert_eq!(boarding_pass_id(boarding_pass("BFFFBBFRRR")), 567);
+        assert_eq!(boarding_pass_id(boarding_pass("FFFBBBFRRR")), 119);
+        assert_eq!(boarding_pass_id(boarding_pass("BBFFBBFRLL")), 820);
+    }
+
+    #[test]
+    fn part2_works() {
+        let input = (8..=24)
+            .filter(|id| *id != 17)
+            .map(encode_boarding_pass)
+            .join("\n");
+        assert_eq!(part2(&input), Ok(17));
+        assert_eq!(part2("FFFFFFBLLL\nFFFFFFBLLR"), Err(Error::NoSolution));
+    }
+
+    #[test]
+    fn resaved_input_works() {
+        for input in &resaved(INPUT) {
+            assert_eq!(part1(input), part1(INPUT));
+            assert_eq!(part2(input), part2(INPUT));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reader_gives_same_results() {
+        for input in &resaved(INPUT) {
+            assert_eq!(part1_from_reader(input.as_bytes()), part1(input));
+            assert_eq!(part2_from_reader(input.as_bytes()), part2(input));
+        }
+        for input in [INPUT, "FBFBBFFRLR\nBFFFBBFRRX\n"] {
+            assert_eq!(part1_from_reader(input.as_bytes()), part1(input));
+            assert_eq!(part2_from_reader(input.as_bytes()), part2(input));
+        }
+        for seed in 0..20 {
+            let input = generate(&mut Rng::new(seed));
+            assert_eq!(part1_from_reader(input.as_bytes()), part1(&input));
+            assert_eq!(part2_from_reader(input.as_bytes()), part2(&input));
+        }
+    }
+
+    fn reference_ids(input: &str) -> Vec<usize> {
+        input
+            .lines()
+            .map(|line| {
+                let binary: String = line
+                    .chars()
+                    .map(|c| if matches!(c, 'B' | 'R') { '1' } else { '0' })
+                    .collect();
+                usize::from_str_radix(&binary, 2).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn random_inputs_match_reference() {
+        for seed in 0..100 {
+            let input = generate(&mut Rng::new(seed));
+            let ids = reference_ids(&input);
+            let max = *ids.iter().max().unwrap();
+            let min = *ids.iter().min().unwrap();
+            let missing = (min..max).find(|id| !ids.contains(id)).unwrap();
+
+            assert_eq!(part1(&input), Ok(max), "seed {seed}");
+            assert_eq!(part2(&input), Ok(missing), "seed {seed}");
+        }
+    }
+}
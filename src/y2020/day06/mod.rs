@@ -0,0 +1,156 @@
+use alloc::{string::String, vec::Vec};
+
+use itertools::Itertools;
+
+use crate::{
+    bitset::BitSet, random::Rng, reduce::Structure, solver::Solver, text::Text, Error, Result,
+};
+
+fn parse_groups(input: &str) -> Result<Vec<&str>> {
+    if let Some(index) = input.find(|c: char| !c.is_ascii_lowercase() && !c.is_whitespace()) {
+        return Err(Error::at(
+            input,
+            &input[index..],
+            "Expected a question from `a` to `z`",
+        ));
+    }
+
+    Ok(input.split("\n\n").collect())
+}
+
+/// The questions answered in `answers`, numbered from `a`.
+fn questions(answers: &str) -> BitSet {
+    answers
+        .bytes()
+        .filter(u8::is_ascii_lowercase)
+        .map(|b| usize::from(b - b'a'))
+        .collect()
+}
+
+fn count_any_answered(groups: &[&str]) -> usize {
+    groups.iter().map(|group| questions(group).len()).sum()
+}
+
+fn count_all_answered(groups: &[&str]) -> usize {
+    groups
+        .iter()
+        .map(|group| {
+            let mut it = group.trim().split('\n').map(questions);
+
+            let mut intersection = it.next().unwrap();
+
+            for questions in it {
+                intersection.intersect_with(&questions);
+            }
+
+            intersection.len()
+        })
+        .sum()
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(count_any_answered(&Solution::parse(&Text::new(input))?))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    Ok(count_all_answered(&Solution::parse(&Text::new(input))?))
+}
+
+/// Generates groups whose members share a random set of common answers.
+pub fn generate(rng: &mut Rng) -> String {
+    let questions: Vec<char> = ('a'..='z').collect();
+
+    (0..rng.range(1..50))
+        .map(|_| {
+            let common_count = rng.range(0..5);
+            let common: Vec<char> = (0..common_count).map(|_| *rng.choose(&questions)).collect();
+
+            (0..rng.range(1..6))
+                .map(|_| {
+                    let mut answers: Vec<char> = Vec::new();
+                    let picked: Vec<char> = (0..rng.range(0..10))
+                        .map(|_| *rng.choose(&questions))
+                        .collect();
+                    for answer in picked.into_iter().chain(common.iter().copied()) {
+                        if !answers.contains(&answer) {
+                            answers.push(answer);
+                        }
+                    }
+                    if answers.is_empty() {
+                        answers.push(*rng.choose(&questions));
+                    }
+                    rng.shuffle(&mut answers);
+                    answers.into_iter().collect::<String>()
+                })
+                .join("\n")
+        })
+        .join("\n\n")
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Vec<&'a str>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const STRUCTURE: Structure = Structure::Blocks { lines: true };
+
+    fn parse<'a>(input: &'a Text) -> Result<Self::Input<'a>> {
+        parse_groups(input)
+    }
+
+    fn part1(groups: &Self::Input<'_>) -> Result<Self::Answer1> {
+        Ok(count_any_answered(groups))
+    }
+
+    fn part2(groups: &Self::Input<'_>) -> Option<Result<Self::Answer2>> {
+        Some(Ok(count_all_answered(groups)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::resaved;
+
+    static TEST_INPUT: &str = include_str!("test_input.txt");
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(part1(TEST_INPUT), Ok(11));
+    }
+
+    #[test]
+    fn part2_works() {
+        assert_eq!(part2(TEST_INPUT), Ok(6));
+    }
+
+    #[test]
+    fn resaved_input_works() {
+        for input in &resaved(TEST_INPUT) {
+            assert_eq!(part1(input), part1(TEST_INPUT));
+            assert_eq!(part2(input), part2(TEST_INPUT));
+        }
+    }
+
+    #[test]
+    fn random_inputs_match_reference() {
+        for seed in 0..100 {
+            let input = generate(&mut Rng::new(seed));
+            let (mut any, mut all) = (0, 0);
+
+            for group in input.split("\n\n") {
+                let answers: Vec<u32> = group
+                    .lines()
+                    .map(|line| line.bytes().fold(0, |set, b| set | 1 << (b - b'a')))
+                    .collect();
+                any += answers.iter().fold(0, |a, b| a | b).count_ones() as usize;
+                all += answers.iter().fold(!0, |a, b| a & b).count_ones() as usize;
+            }
+
+            assert_eq!(part1(&input), Ok(any), "seed {seed}");
+            assert_eq!(part2(&input), Ok(all), "seed {seed}");
+        }
+    }
+}
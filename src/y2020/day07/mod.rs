@@ -0,0 +1,340 @@
+use alloc::{borrow::Cow, format, string::String, vec::Vec};
+
+use itertools::Itertools;
+
+use crate::{
+    collections::HashMap, graph::Graph, parser::Parser, random::Rng, solver::Solver, text::Text,
+    Error, Result,
+};
+
+const SHINY_GOLD: &str = "shiny gold";
+
+/// The bags each bag directly contains, with their counts.
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Data<'a>(pub HashMap<Cow<'a, str>, HashMap<Cow<'a, str>, usize>>);
+
+/// Parses `<count> <color> bag` or `<count> <color> bags`.
+fn parse_content<'a>(p: &mut Parser<'a>) -> Result<(Cow<'a, str>, usize)> {
+    let count = p.number()?;
+    p.tag(" ")?;
+    let color = p.take_until(" bag")?;
+    p.tag(" bag")?;
+    p.eat("s");
+
+    Ok((color.into(), count))
+}
+
+impl<'a> Data<'a> {
+    fn parse(input: &'a str) -> Result<Self> {
+        let data = Parser::new(input).lines(|p| {
+            let color = p
+                .take_until(" bags contain ")
+                .map_err(|_| p.error("Expected `<color> bags contain`"))?;
+            p.tag(" bags contain ")?;
+
+            let color_counts = if p.eat("no other bags") {
+                HashMap::default()
+            } else {
+                p.separated(", ", parse_content)?.into_iter().collect()
+            };
+            p.tag(".")?;
+
+            Ok((color.into(), color_counts))
+        })?;
+
+        Ok(Data(data.into_iter().collect()))
+    }
+}
+
+/// Puzzle constants, defaulting to those of the actual puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    /// The color of your bag.
+    pub color: String,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            color: SHINY_GOLD.into(),
+        }
+    }
+}
+
+/// The graph of the bags each bag directly contains, weighted by their counts, with the node of
+/// each color.
+fn graph<'a>(data: &'a Data) -> (Graph<usize>, HashMap<&'a str, usize>) {
+    let mut nodes: HashMap<&str, usize> = HashMap::default();
+    for (color, color_counts) in data.0.iter() {
+        for color in core::iter::once(color).chain(color_counts.keys()) {
+            let node_count = nodes.len();
+            nodes.entry(&**color).or_insert(node_count);
+        }
+    }
+
+    let mut graph = Graph::new(nodes.len());
+    for (color, color_counts) in data.0.iter() {
+        for (inner_color, count) in color_counts {
+            graph.add_edge(nodes[&**color], nodes[&**inner_color], *count);
+        }
+    }
+
+    (graph, nodes)
+}
+
+fn count_colors_containing(data: &Data, target: &str) -> usize {
+    let (graph, nodes) = graph(data);
+    let Some(&target) = nodes.get(target) else {
+        return 0;
+    };
+
+    // Starts from the bags that directly contain the target, which counts the target itself only
+    // if it is inside itself
+    let containers = graph.reversed();
+    let direct_containers: Vec<usize> = containers
+        .successors(target)
+        .map(|(node, _)| node)
+        .collect();
+    containers.bfs(direct_containers).count()
+}
+
+fn count_bags_inside(data: &Data, color: &str) -> Result<usize> {
+    let (graph, nodes) = graph(data);
+    let Some(&start) = nodes.get(color) else {
+        return Ok(0);
+    };
+
+    // A bag that ends up inside itself holds infinitely many bags
+    let counts = graph
+        .path_counts(start, |count| *count as u64)
+        .map_err(|_| Error::NoSolution)?;
+
+    // Every path from the bag leads to a bag inside it, except for the empty one
+    let bags = counts
+        .into_iter()
+        .try_fold(0u64, |sum, count| sum.checked_add(count?))
+        .ok_or(Error::NoSolution)?;
+    usize::try_from(bags - 1).map_err(|_| Error::NoSolution)
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    part1_with(input, &Params::default())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    part2_with(input, &Params::default())
+}
+
+pub fn part1_with(input: &str, params: &Params) -> Result<usize> {
+    Ok(count_colors_containing(
+        &Solution::parse(&Text::new(input))?,
+        &params.color,
+    ))
+}
+
+pub fn part2_with(input: &str, params: &Params) -> Result<usize> {
+    count_bags_inside(&Solution::parse(&Text::new(input))?, &params.color)
+}
+
+/// Generates acyclic bag rules that include shiny gold bags.
+pub fn generate(rng: &mut Rng) -> String {
+    const ADJECTIVES: [&str; 12] = [
+        "light", "dark", "bright", "muted", "faded", "dotted", "vibrant", "plaid", "pale", "wavy",
+        "drab", "dim",
+    ];
+    const COLORS: [&str; 12] = [
+        "red", "orange", "white", "yellow", "olive", "plum", "blue", "black", "green", "teal",
+        "tan", "cyan",
+    ];
+
+    let mut colors: Vec<String> = ADJECTIVES
+        .iter()
+        .cartesian_product(COLORS)
+        .map(|(adjective, color)| format!("{adjective} {color}"))
+        .collect();
+    rng.shuffle(&mut colors);
+    colors.truncate(rng.range(4..25));
+
+    let shiny_gold_index = rng.range(0..colors.len());
+    colors.insert(shiny_gold_index, SHINY_GOLD.into());
+
+    // Bags only contain bags later in `colors`, which keeps the rules acyclic
+    let mut rules: Vec<String> = (0..colors.len())
+        .map(|index| {
+            let later = colors.len() - index - 1;
+            let mut contents: Vec<usize> = (index + 1..colors.len()).collect();
+            rng.shuffle(&mut contents);
+            contents.truncate(rng.range(0..later.min(3) + 1));
+
+            let contents = if contents.is_empty() {
+                "no other bags".into()
+            } else {
+                contents
+                    .into_iter()
+                    .map(|content| match rng.range(1..5) {
+                        1 => format!("1 {} bag", colors[content]),
+                        count => format!("{count} {} bags", colors[content]),
+                    })
+                    .join(", ")
+            };
+
+            format!("{} bags contain {contents}.", colors[index])
+        })
+        .collect();
+
+    rng.shuffle(&mut rules);
+    rules.join("\n")
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Data<'a>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<'a>(input: &'a Text) -> Result<Self::Input<'a>> {
+        Data::parse(input)
+    }
+
+    fn part1(data: &Self::Input<'_>) -> Result<Self::Answer1> {
+        Ok(count_colors_containing(data, SHINY_GOLD))
+    }
+
+    fn part2(data: &Self::Input<'_>) -> Option<Result<Self::Answer2>> {
+        Some(count_bags_inside(data, SHINY_GOLD))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::resaved;
+    use std::collections::HashSet;
+
+    static TEST_INPUT: &str = include_str!("test_input.txt");
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(part1(TEST_INPUT), Ok(4));
+    }
+
+    #[test]
+    fn part2_works() {
+        assert_eq!(part2(TEST_INPUT), Ok(32));
+    }
+
+    #[test]
+    fn resaved_input_works() {
+        for input in &resaved(TEST_INPUT) {
+            assert_eq!(part1(input), part1(TEST_INPUT));
+            assert_eq!(part2(input), part2(TEST_INPUT));
+        }
+    }
+
+    #[test]
+    fn params_change_the_color() {
+        let params = Params {
+            color: "dark olive".into(),
+        };
+        assert_eq!(part1_with(TEST_INPUT, &params), Ok(5));
+        assert_eq!(part2_with(TEST_INPUT, &params), Ok(7));
+    }
+
+    #[test]
+    fn invalid_input_is_reported() {
+        assert_eq!(
+            part1("shiny gold bags contain no other bags.\nfaded blue bags hold 1 shiny gold bag."),
+            Err(Error::parse(2, 1, "Expected `<color> bags contain`"))
+        );
+        assert!(matches!(
+            part2("shiny gold bags contain 99999999999999999999 faded blue bags."),
+            Err(Error::Parse {
+                line: 1,
+                column: 25,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn bags_inside_themselves_have_no_count() {
+        let input =
+            "shiny gold bags contain 1 faded blue bag.\nfaded blue bags contain 2 shiny gold bags.";
+        assert_eq!(part1(input), Ok(2));
+        assert_eq!(part2(input), Err(Error::NoSolution));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn data_round_trips_through_json() {
+        let text = Text::new(TEST_INPUT);
+        let data = Solution::parse(&text).unwrap();
+        let json = serde_json::to_string(&data).unwrap();
+        assert_eq!(serde_json::from_str::<Data>(&json).unwrap(), data);
+
+        let data = serde_json::from_str(r#"{"shiny gold": {"dark\u0020red": 2}, "dark red": {}}"#);
+        assert_eq!(Solution::part2(&data.unwrap()), Some(Ok(2)));
+    }
+
+    fn reference_counts(input: &str) -> (usize, usize) {
+        let rules: HashMap<&str, Vec<(usize, &str)>> = input
+            .lines()
+            .map(|line| {
+                let (color, contents) = line.split_once(" bags contain ").unwrap();
+                let contents = contents
+                    .trim_end_matches('.')
+                    .split(", ")
+                    .filter(|content| *content != "no other bags")
+                    .map(|content| {
+                        let (count, rest) = content.split_once(' ').unwrap();
+                        let color = rest.rsplit_once(' ').unwrap().0;
+                        (count.parse().unwrap(), color)
+                    })
+                    .collect();
+                (color, contents)
+            })
+            .collect();
+
+        let mut holders = HashSet::new();
+        let mut queue = vec!["shiny gold"];
+        while let Some(color) = queue.pop() {
+            for (holder, contents) in &rules {
+                if contents.iter().any(|(_, content)| *content == color) && holders.insert(*holder)
+                {
+                    queue.push(holder);
+                }
+            }
+        }
+
+        fn bag_count<'a>(
+            rules: &HashMap<&'a str, Vec<(usize, &'a str)>>,
+            counts: &mut HashMap<&'a str, usize>,
+            color: &'a str,
+        ) -> usize {
+            if let Some(count) = counts.get(color) {
+                return *count;
+            }
+            let count = rules[color]
+                .iter()
+                .map(|(count, content)| count * (1 + bag_count(rules, counts, content)))
+                .sum();
+            counts.insert(color, count);
+            count
+        }
+
+        let inside = bag_count(&rules, &mut HashMap::default(), "shiny gold");
+        (holders.len(), inside)
+    }
+
+    #[test]
+    fn random_inputs_match_reference() {
+        for seed in 0..100 {
+            let input = generate(&mut Rng::new(seed));
+            let (expected1, expected2) = reference_counts(&input);
+            assert_eq!(part1(&input), Ok(expected1), "seed {seed}");
+            assert_eq!(part2(&input), Ok(expected2), "seed {seed}");
+        }
+    }
+}
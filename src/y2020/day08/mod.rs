@@ -0,0 +1,398 @@
+use alloc::{boxed::Box, format, string::String, vec::Vec};
+
+use itertools::Itertools;
+
+use crate::{
+    bitset::BitSet,
+    error::parse_at,
+    observer::{notify, Ignore, Observer},
+    random::Rng,
+    solver::Solver,
+    text::Text,
+    Error, Result,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum Instruction {
+    Nop(i64),
+    Acc(i64),
+    Jmp(i64),
+}
+
+impl Instruction {
+    fn parse(input: &str, line: &str) -> Result<Self> {
+        let (opcode, val) = line
+            .split_once(' ')
+            .ok_or_else(|| Error::at(input, line, "Expected `<opcode> <value>`"))?;
+        let val = parse_at(input, val.strip_prefix('+').unwrap_or(val))?;
+        match opcode {
+            "nop" => Ok(Instruction::Nop(val)),
+            "acc" => Ok(Instruction::Acc(val)),
+            "jmp" => Ok(Instruction::Jmp(val)),
+            _ => Err(Error::at(
+                input,
+                opcode,
+                format!("Invalid opcode {opcode:?}"),
+            )),
+        }
+    }
+}
+
+/// The state of a running program.
+#[derive(Debug, Clone)]
+pub struct Cpu {
+    program: Box<[Instruction]>,
+    visited: BitSet,
+    pc: usize,
+    acc: i64,
+}
+
+enum CpuState {
+    Running,
+    Looping(i64),
+    Terminated(i64),
+    /// The accumulator overflowed, or a jump left the program before its first instruction.
+    Crashed,
+}
+
+impl Cpu {
+    pub fn from(program: impl Into<Box<[Instruction]>>) -> Self {
+        let program = program.into();
+        Cpu {
+            visited: BitSet::with_capacity(program.len()),
+            program,
+            pc: 0,
+            acc: 0,
+        }
+    }
+
+    pub fn program(&self) -> &[Instruction] {
+        &self.program
+    }
+
+    /// The index of the next instruction.
+    pub fn pc(&self) -> usize {
+        self.pc
+    }
+
+    pub fn acc(&self) -> i64 {
+        self.acc
+    }
+
+    fn step(&mut self) -> CpuState {
+        if let Some(instr) = self.program.get(self.pc) {
+            if !self.visited.insert(self.pc) {
+                return CpuState::Looping(self.acc);
+            }
+
+            match instr {
+                Instruction::Nop(_) => {
+                    self.pc += 1;
+                }
+                Instruction::Acc(val) => {
+                    let Some(acc) = self.acc.checked_add(*val) else {
+                        return CpuState::Crashed;
+                    };
+                    self.acc = acc;
+                    self.pc += 1;
+                }
+                Instruction::Jmp(val) => {
+                    let pc = (self.pc as i64).checked_add(*val);
+                    let Some(pc) = pc.and_then(|pc| usize::try_from(pc).ok()) else {
+                        return CpuState::Crashed;
+                    };
+                    self.pc = pc;
+                }
+            }
+
+            CpuState::Running
+        } else {
+            CpuState::Terminated(self.acc)
+        }
+    }
+}
+
+/// Runs `program` until it terminates or loops, passing the CPU to `observer` after each
+/// instruction.
+fn run(program: &[Instruction], observer: &mut impl Observer<Cpu>) -> Result<CpuState> {
+    let mut cpu = Cpu::from(program);
+
+    loop {
+        match cpu.step() {
+            CpuState::Running => notify(observer, &cpu)?,
+            state => break Ok(state),
+        }
+    }
+}
+
+fn run_until_loop(program: &[Instruction], observer: &mut impl Observer<Cpu>) -> Result<i64> {
+    match run(program, observer)? {
+        CpuState::Looping(val) => Ok(val),
+        _ => Err(Error::NoSolution),
+    }
+}
+
+/// Runs each repaired program in turn, observing all of them.
+fn run_repaired(program: &[Instruction], observer: &mut impl Observer<Cpu>) -> Result<i64> {
+    for (index, instruction) in program.iter().enumerate() {
+        let instruction = match *instruction {
+            Instruction::Nop(val) => Instruction::Jmp(val),
+            Instruction::Jmp(val) => Instruction::Nop(val),
+            _ => continue,
+        };
+
+        let mut program = program.to_vec();
+        program[index] = instruction;
+
+        if let CpuState::Terminated(val) = run(&program, observer)? {
+            return Ok(val);
+        }
+    }
+
+    Err(Error::NoSolution)
+}
+
+pub fn part1(input: &str) -> Result<i64> {
+    part1_observed(input, &mut Ignore)
+}
+
+pub fn part2(input: &str) -> Result<i64> {
+    part2_observed(input, &mut Ignore)
+}
+
+/// Like [`part1`], but passes the CPU to `observer` after each instruction.
+pub fn part1_observed(input: &str, observer: &mut impl Observer<Cpu>) -> Result<i64> {
+    run_until_loop(&Solution::parse(&Text::new(input))?, observer)
+}
+
+/// Like [`part2`], but passes the CPU to `observer` after each instruction of each repaired
+/// program that is tried.
+pub fn part2_observed(input: &str, observer: &mut impl Observer<Cpu>) -> Result<i64> {
+    run_repaired(&Solution::parse(&Text::new(input))?, observer)
+}
+
+/// Generates a looping program where swapping exactly one `jmp` or `nop` makes it terminate.
+pub fn generate(rng: &mut Rng) -> String {
+    loop {
+        let len = rng.range(5..50);
+        let program: Vec<Instruction> = (0..len)
+            .map(|index| {
+                let offset = rng.range(0..len + 1) as i64 - index as i64;
+                match rng.range(0..10) {
+                    0..=3 => Instruction::Acc(rng.range(0..100) as i64 - 50),
+                    4..=5 => Instruction::Nop(offset),
+                    _ => Instruction::Jmp(offset),
+                }
+            })
+            .collect();
+
+        let repairs = (0..len)
+            .filter(|index| {
+                let mut repaired = program.clone();
+                repaired[*index] = match program[*index] {
+                    Instruction::Nop(val) => Instruction::Jmp(val),
+                    Instruction::Jmp(val) => Instruction::Nop(val),
+                    Instruction::Acc(_) => return false,
+                };
+                matches!(run(&repaired, &mut Ignore), Ok(CpuState::Terminated(_)))
+            })
+            .count();
+
+        if matches!(run(&program, &mut Ignore), Ok(CpuState::Looping(_))) && repairs == 1 {
+            break program
+                .iter()
+                .map(|instruction| match instruction {
+                    Instruction::Nop(val) => format!("nop {val:+}"),
+                    Instruction::Acc(val) => format!("acc {val:+}"),
+                    Instruction::Jmp(val) => format!("jmp {val:+}"),
+                })
+                .join("\n");
+        }
+    }
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Vec<Instruction>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse<'a>(input: &'a Text) -> Result<Self::Input<'a>> {
+        input
+            .lines()
+            .map(|line| Instruction::parse(input, line))
+            .collect()
+    }
+
+    fn part1(program: &Self::Input<'_>) -> Result<Self::Answer1> {
+        run_until_loop(program, &mut Ignore)
+    }
+
+    fn part2(program: &Self::Input<'_>) -> Option<Result<Self::Answer2>> {
+        Some(run_repaired(program, &mut Ignore))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::observer::Trace;
+    use crate::text::resaved;
+    use std::ops::ControlFlow;
+
+    static TEST_INPUT: &str = include_str!("test_input.txt");
+    static INPUT: &str = include_str!("input.txt");
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(part1(TEST_INPUT), Ok(5));
+    }
+
+    #[test]
+    fn part2_works() {
+        assert_eq!(part2(TEST_INPUT), Ok(8));
+    }
+
+    #[test]
+    fn resaved_input_works() {
+        for input in &resaved(TEST_INPUT) {
+            assert_eq!(part1(input), part1(TEST_INPUT));
+            assert_eq!(part2(input), part2(TEST_INPUT));
+        }
+    }
+
+    #[test]
+    fn invalid_input_is_reported() {
+        assert_eq!(
+            part1("nop +0\nmul +1\n"),
+            Err(Error::parse(2, 1, "Invalid opcode \"mul\""))
+        );
+        assert_eq!(
+            part1("nop +0\nacc\n"),
+            Err(Error::parse(2, 1, "Expected `<opcode> <value>`"))
+        );
+        assert_eq!(part1("nop +0\nacc +1\n"), Err(Error::NoSolution));
+    }
+
+    #[test]
+    fn overflows_crash_the_program() {
+        let input = "acc +9223372036854775807\nacc +1\njmp -2";
+        assert_eq!(part1(input), Err(Error::NoSolution));
+        assert_eq!(part2(input), Err(Error::NoSolution));
+
+        let input = "nop +0\njmp -9223372036854775808\nacc +1";
+        assert_eq!(part1(input), Err(Error::NoSolution));
+        assert_eq!(part2(input), Ok(1));
+
+        assert_eq!(part1("jmp -2\njmp -1"), Err(Error::NoSolution));
+        assert_eq!(
+            part1("nop +0\njmp +9223372036854775807"),
+            Err(Error::NoSolution)
+        );
+    }
+
+    #[test]
+    fn observer_sees_each_instruction() {
+        let mut trace = Trace::new();
+        assert_eq!(part1_observed(TEST_INPUT, &mut trace), Ok(5));
+        assert_eq!(
+            trace.states.iter().map(Cpu::pc).collect_vec(),
+            [1, 2, 6, 7, 3, 4, 1]
+        );
+        assert_eq!(
+            trace.states.iter().map(Cpu::acc).collect_vec(),
+            [0, 1, 1, 2, 2, 5, 5]
+        );
+
+        let mut steps = 0;
+        let mut stop_after_100 = |_: &Cpu| {
+            steps += 1;
+            if steps < 100 {
+                ControlFlow::Continue(())
+            } else {
+                ControlFlow::Break(())
+            }
+        };
+        assert_eq!(
+            part2_observed(INPUT, &mut stop_after_100),
+            Err(Error::Stopped)
+        );
+        assert_eq!(steps, 100);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn instructions_round_trip_through_json() {
+        let program = Solution::parse(&Text::new(TEST_INPUT)).unwrap();
+        let json = serde_json::to_string(&program).unwrap();
+        assert!(json.starts_with(r#"[{"nop":0},{"acc":1},{"jmp":4}"#));
+        assert_eq!(
+            serde_json::from_str::<Vec<Instruction>>(&json).unwrap(),
+            program
+        );
+
+        let program = serde_json::from_str(r#"[{"acc": 3}, {"jmp": -1}]"#).unwrap();
+        assert_eq!(Solution::part1(&program), Ok(3));
+    }
+
+    /// Runs `program`, returning the accumulator and whether it terminated, or `None` if it
+    /// crashed.
+    fn reference_run(program: &[(&str, i64)]) -> Option<(i64, bool)> {
+        let (mut pc, mut acc) = (0, 0);
+        let mut visited = vec![false; program.len()];
+
+        while pc < program.len() && !visited[pc] {
+            visited[pc] = true;
+            let (opcode, val) = program[pc];
+            match opcode {
+                "acc" => acc = i64::checked_add(acc, val)?,
+                "jmp" => {
+                    pc = usize::try_from((pc as i64).checked_add(val)?).ok()?;
+                    continue;
+                }
+                _ => {}
+            }
+            pc += 1;
+        }
+
+        Some((acc, pc >= program.len()))
+    }
+
+    #[test]
+    fn random_inputs_match_reference() {
+        for seed in 0..100 {
+            let input = generate(&mut Rng::new(seed));
+            let program: Vec<(&str, i64)> = input
+                .lines()
+                .map(|line| (&line[..3], line[4..].parse().unwrap()))
+                .collect();
+
+            let repaired: Vec<i64> = (0..program.len())
+                .filter_map(|index| {
+                    let mut repaired = program.clone();
+                    repaired[index].0 = match program[index].0 {
+                        "nop" => "jmp",
+                        "jmp" => "nop",
+                        _ => return None,
+                    };
+                    let (acc, terminated) = reference_run(&repaired)?;
+                    terminated.then_some(acc)
+                })
+                .collect();
+
+            assert_eq!(repaired.len(), 1, "seed {seed}");
+            assert_eq!(
+                part1(&input),
+                Ok(reference_run(&program).unwrap().0),
+                "seed {seed}"
+            );
+            assert_eq!(part2(&input), Ok(repaired[0]), "seed {seed}");
+        }
+    }
+}
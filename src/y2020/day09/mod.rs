@@ -0,0 +1,309 @@
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "std")]
+use std::{
+    collections::VecDeque,
+    io::{BufRead, Seek},
+};
+
+use itertools::Itertools;
+
+#[cfg(feature = "std")]
+use crate::stream::Lines;
+use crate::{error::parse_at, random::Rng, solver::Solver, text::Text, Error, Result};
+
+const PREAMBLE_LENGTH: usize = 25;
+
+fn is_pair_sum(preamble: &[u64], number: u64) -> bool {
+    preamble
+        .iter()
+        .tuple_combinations()
+        .any(|(a, b)| a != b && a.checked_add(*b) == Some(number))
+}
+
+fn find_first_invalid_number(numbers: &[u64], preamble_length: usize) -> Option<u64> {
+    numbers.windows(preamble_length + 1).find_map(|window| {
+        let (last, preamble) = window.split_last()?;
+        if !is_pair_sum(preamble, *last) {
+            Some(*last)
+        } else {
+            None
+        }
+    })
+}
+
+fn find_encryption_weakness(numbers: &[u64], preamble_length: usize) -> Result<u64> {
+    // `sums[i]` is the sum of the first `i` numbers, which can be larger than any of them
+    let sums: Vec<u128> = core::iter::once(0)
+        .chain(numbers.iter().scan(0, |sum, num| {
+            *sum += u128::from(*num);
+            Some(*sum)
+        }))
+        .collect();
+
+    let first_invalid_number =
+        find_first_invalid_number(numbers, preamble_length).ok_or(Error::NoSolution)?;
+
+    for start in 0..numbers.len().saturating_sub(1) {
+        // The sums of ranges from `start` grow with their end, and a range has at least two numbers.
+        // Zeros make some sums equal, and the earliest end is taken, as the reader does
+        let target = sums[start] + u128::from(first_invalid_number);
+
+        let end = start + 2 + sums[start + 2..].partition_point(|sum| *sum < target);
+        if sums.get(end) == Some(&target) {
+            let (min, max) = numbers[start..end].iter().minmax().into_option().unwrap();
+            return Ok(min + max);
+        }
+    }
+
+    Err(Error::NoSolution)
+}
+
+pub fn part1(input: &str, preamble_length: usize) -> Result<u64> {
+    find_first_invalid_number(&Solution::parse(&Text::new(input))?, preamble_length)
+        .ok_or(Error::NoSolution)
+}
+
+pub fn part2(input: &str, preamble_length: usize) -> Result<u64> {
+    find_encryption_weakness(&Solution::parse(&Text::new(input))?, preamble_length)
+}
+
+#[cfg(feature = "std")]
+fn next_number(lines: &mut Lines<impl BufRead>) -> Result<Option<u64>> {
+    lines.parse_next(|line| parse_at(line, line))
+}
+
+/// Reads numbers up to the first invalid one, which is returned, keeping only the preamble.
+#[cfg(feature = "std")]
+fn read_first_invalid_number(
+    lines: &mut Lines<impl BufRead>,
+    preamble_length: usize,
+) -> Result<u64> {
+    let mut preamble = VecDeque::with_capacity(preamble_length);
+
+    while let Some(number) = next_number(lines)? {
+        if preamble.len() == preamble_length {
+            if !is_pair_sum(preamble.make_contiguous(), number) {
+                return Ok(number);
+            }
+            preamble.pop_front();
+        }
+        preamble.push_back(number);
+    }
+
+    Err(Error::NoSolution)
+}
+
+/// Like [`part1`], but reads the input line by line, keeping only the preamble, and stops reading
+/// at the answer.
+#[cfg(feature = "std")]
+pub fn part1_from_reader(reader: impl BufRead, preamble_length: usize) -> Result<u64> {
+    read_first_invalid_number(&mut Lines::new(reader), preamble_length)
+}
+
+/// Like [`part2`], but reads the input line by line, keeping only the preamble and then the range
+/// being summed. The input is read twice: once to find the invalid number, and again from the
+/// start to find the range that sums to it.
+#[cfg(feature = "std")]
+pub fn part2_from_reader(mut reader: impl BufRead + Seek, preamble_length: usize) -> Result<u64> {
+    let target = read_first_invalid_number(&mut Lines::new(&mut reader), preamble_length)?;
+    let target = u128::from(target);
+
+    reader.rewind()?;
+    let mut lines = Lines::new(reader);
+
+    // The current range, which has at least two numbers, and its sum
+    let mut range = VecDeque::new();
+    let mut sum = 0u128;
+    loop {
+        while sum < target || range.len() < 2 {
+            let number = next_number(&mut lines)?.ok_or(Error::NoSolution)?;
+            range.push_back(number);
+            sum += u128::from(number);
+        }
+
+        if sum == target {
+            let (min, max) = range.iter().minmax().into_option().unwrap();
+            return Ok(min + max);
+        }
+
+        sum -= u128::from(range.pop_front().unwrap());
+    }
+}
+
+fn push_valid_number(rng: &mut Rng, numbers: &mut Vec<u64>) {
+    let preamble = &numbers[numbers.len() - PREAMBLE_LENGTH..];
+    let (a, b) = loop {
+        let (a, b) = (rng.choose(preamble), rng.choose(preamble));
+        if a != b {
+            break (a, b);
+        }
+    };
+    numbers.push(a + b);
+}
+
+/// Generates numbers with a preamble of 25, where the first invalid number is the sum of a
+/// contiguous range of earlier numbers.
+pub fn generate(rng: &mut Rng) -> String {
+    let mut numbers: Vec<u64> = (1..100).collect();
+    rng.shuffle(&mut numbers);
+    numbers.truncate(PREAMBLE_LENGTH);
+
+    for _ in 0..rng.range(1..50) {
+        push_valid_number(rng, &mut numbers);
+    }
+
+    let invalid_number = loop {
+        let len = rng.range(2..6);
+        let start = rng.range(0..numbers.len() - len);
+        let sum = numbers[start..start + len].iter().sum();
+        if !is_pair_sum(&numbers[numbers.len() - PREAMBLE_LENGTH..], sum) {
+            break sum;
+        }
+    };
+    numbers.push(invalid_number);
+
+    for _ in 0..rng.range(0..10) {
+        push_valid_number(rng, &mut numbers);
+    }
+
+    numbers.iter().join("\n")
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse<'a>(input: &'a Text) -> Result<Self::Input<'a>> {
+        input.lines().map(|line| parse_at(input, line)).collect()
+    }
+
+    fn part1(numbers: &Self::Input<'_>) -> Result<Self::Answer1> {
+        find_first_invalid_number(numbers, PREAMBLE_LENGTH).ok_or(Error::NoSolution)
+    }
+
+    fn part2(numbers: &Self::Input<'_>) -> Option<Result<Self::Answer2>> {
+        Some(find_encryption_weakness(numbers, PREAMBLE_LENGTH))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::resaved;
+
+    static TEST_INPUT: &str = include_str!("test_input.txt");
+    #[cfg(feature = "std")]
+    static INPUT: &str = include_str!("input.txt");
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(part1(TEST_INPUT, 5), Ok(127));
+    }
+
+    #[test]
+    fn part2_works() {
+        assert_eq!(part2(TEST_INPUT, 5), Ok(62));
+    }
+
+    #[test]
+    fn resaved_input_works() {
+        for input in &resaved(TEST_INPUT) {
+            assert_eq!(part1(input, 5), part1(TEST_INPUT, 5));
+            assert_eq!(part2(input, 5), part2(TEST_INPUT, 5));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reader_gives_same_results() {
+        use std::io::Cursor;
+
+        for input in &resaved(TEST_INPUT) {
+            assert_eq!(part1_from_reader(input.as_bytes(), 5), part1(input, 5));
+            assert_eq!(part2_from_reader(Cursor::new(input), 5), part2(input, 5));
+        }
+        for (input, preamble_length) in [(TEST_INPUT, 5), (INPUT, PREAMBLE_LENGTH)] {
+            assert_eq!(
+                part1_from_reader(input.as_bytes(), preamble_length),
+                part1(input, preamble_length)
+            );
+            assert_eq!(
+                part2_from_reader(Cursor::new(input), preamble_length),
+                part2(input, preamble_length)
+            );
+        }
+        for seed in 0..20 {
+            let input = generate(&mut Rng::new(seed));
+            assert_eq!(
+                part1_from_reader(input.as_bytes(), PREAMBLE_LENGTH),
+                part1(&input, PREAMBLE_LENGTH)
+            );
+            assert_eq!(
+                part2_from_reader(Cursor::new(&input), PREAMBLE_LENGTH),
+                part2(&input, PREAMBLE_LENGTH)
+            );
+        }
+
+        assert_eq!(
+            part1_from_reader("1\n2\nx\n".as_bytes(), 2),
+            part1("1\n2\nx\n", 2)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reader_takes_the_same_range_with_zeros() {
+        use std::io::Cursor;
+
+        assert_eq!(part2("1\n2\n4\n0\n7", 4), Ok(5));
+        assert_eq!(part2_from_reader(Cursor::new("1\n2\n4\n0\n7"), 4), Ok(5));
+
+        for seed in 0..200 {
+            let mut rng = Rng::new(seed);
+            let input = (0..rng.range(5..30))
+                .map(|_| rng.range(0..6).to_string())
+                .join("\n");
+            assert_eq!(
+                part2_from_reader(Cursor::new(&input), 3),
+                part2(&input, 3),
+                "seed {seed}"
+            );
+        }
+    }
+
+    #[test]
+    fn random_inputs_match_reference() {
+        for seed in 0..100 {
+            let input = generate(&mut Rng::new(seed));
+            let numbers: Vec<u64> = input.lines().map(|line| line.parse().unwrap()).collect();
+
+            let invalid = (25..numbers.len())
+                .map(|i| numbers[i])
+                .zip(numbers.windows(25))
+                .find(|(number, preamble)| {
+                    !preamble
+                        .iter()
+                        .enumerate()
+                        .any(|(i, a)| preamble[i + 1..].iter().any(|b| a != b && a + b == *number))
+                })
+                .unwrap()
+                .0;
+
+            let weakness = (0..numbers.len())
+                .find_map(|start| {
+                    (start + 2..=numbers.len()).find_map(|end| {
+                        let range = &numbers[start..end];
+                        (range.iter().sum::<u64>() == invalid)
+                            .then(|| range.iter().min().unwrap() + range.iter().max().unwrap())
+                    })
+                })
+                .unwrap();
+
+            assert_eq!(part1(&input, 25), Ok(invalid), "seed {seed}");
+            assert_eq!(part2(&input, 25), Ok(weakness), "seed {seed}");
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+use itertools::Itertools;
+
+use crate::{
+    collections::HashMap, error::parse_at, graph::Graph, random::Rng, solver::Solver, text::Text,
+    Error, Result,
+};
+
+fn adapters(input: &str) -> Result<Box<[u64]>> {
+    let mut numbers: Vec<u64> = input
+        .lines()
+        .map(|line| parse_at(input, line))
+        .collect::<Result<_>>()?;
+    numbers.push(0);
+    let device = numbers.iter().max().unwrap().checked_add(3);
+    numbers.push(device.ok_or(Error::NoSolution)?);
+    numbers.sort_unstable();
+    Ok(numbers.into())
+}
+
+fn joltage_difference_product(numbers: &[u64]) -> u64 {
+    let mut groups: HashMap<u64, u64> = HashMap::default();
+    let mut it = numbers.windows(2);
+    while let Some(&[a, b]) = it.next() {
+        let diff = b - a;
+        *groups.entry(diff).or_default() += 1;
+    }
+
+    groups.get(&1).unwrap_or(&0) * groups.get(&3).unwrap_or(&0)
+}
+
+/// Counts the ways to chain adapters from the outlet to the device, where each joltage is one to
+/// three higher than the one before.
+fn count_arrangements(numbers: &[u64]) -> Result<u64> {
+    // The numbers are sorted, so the adapters that can follow each one come right after it
+    let mut graph = Graph::new(numbers.len());
+    for (index, number) in numbers.iter().enumerate() {
+        for (next_index, next_number) in numbers.iter().enumerate().skip(index + 1) {
+            match next_number - number {
+                0 => {}
+                1..=3 => graph.add_edge(index, next_index, ()),
+                _ => break,
+            }
+        }
+    }
+
+    let counts = graph.path_counts(0, |_| 1).map_err(|_| Error::NoSolution)?;
+    counts[numbers.len() - 1].ok_or(Error::NoSolution)
+}
+
+pub fn part1(input: &str) -> Result<u64> {
+    Ok(joltage_difference_product(&Solution::parse(&Text::new(
+        input,
+    ))?))
+}
+
+pub fn part2(input: &str) -> Result<u64> {
+    count_arrangements(&Solution::parse(&Text::new(input))?)
+}
+
+/// Generates adapters with joltage differences of one to three.
+pub fn generate(rng: &mut Rng) -> String {
+    // Longer chains of single differences overflow the number of arrangements
+    let mut joltage = 0;
+    let mut adapters: Vec<u64> = (0..rng.range(1..70))
+        .map(|_| {
+            joltage += *rng.choose(&[1, 1, 1, 1, 1, 1, 2, 3, 3, 3]);
+            joltage
+        })
+        .collect();
+
+    rng.shuffle(&mut adapters);
+    adapters.iter().join("\n")
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Box<[u64]>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse<'a>(input: &'a Text) -> Result<Self::Input<'a>> {
+        adapters(input)
+    }
+
+    fn part1(numbers: &Self::Input<'_>) -> Result<Self::Answer1> {
+        Ok(joltage_difference_product(numbers))
+    }
+
+    fn part2(numbers: &Self::Input<'_>) -> Option<Result<Self::Answer2>> {
+        Some(count_arrangements(numbers))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::resaved;
+
+    static TEST_INPUT_0: &str = include_str!("test_input_0.txt");
+    static TEST_INPUT_1: &str = include_str!("test_input_1.txt");
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(part1(TEST_INPUT_0), Ok(7 * 5));
+        assert_eq!(part1(TEST_INPUT_1), Ok(22 * 10));
+    }
+
+    #[test]
+    fn part2_works() {
+        assert_eq!(part2(TEST_INPUT_0), Ok(8));
+        assert_eq!(part2(TEST_INPUT_1), Ok(19_208));
+    }
+
+    #[test]
+    fn resaved_input_works() {
+        for input in &resaved(TEST_INPUT_0) {
+            assert_eq!(part1(input), part1(TEST_INPUT_0));
+            assert_eq!(part2(input), part2(TEST_INPUT_0));
+        }
+    }
+
+    #[test]
+    fn random_inputs_match_reference() {
+        for seed in 0..100 {
+            let input = generate(&mut Rng::new(seed));
+            let mut joltages: Vec<u64> = input.lines().map(|line| line.parse().unwrap()).collect();
+            joltages.push(0);
+            joltages.sort_unstable();
+            joltages.push(joltages[joltages.len() - 1] + 3);
+
+            let differences = |size| joltages.windows(2).filter(|w| w[1] - w[0] == size).count();
+            let product = (differences(1) * differences(3)) as u64;
+
+            let mut arrangements = vec![0u64; joltages.len()];
+            arrangements[0] = 1;
+            for i in 1..joltages.len() {
+                for j in i.saturating_sub(3)..i {
+                    if joltages[i] - joltages[j] <= 3 {
+                        arrangements[i] += arrangements[j];
+                    }
+                }
+            }
+
+            assert_eq!(part1(&input), Ok(product), "seed {seed}");
+            assert_eq!(
+                part2(&input),
+                Ok(arrangements[joltages.len() - 1]),
+                "seed {seed}"
+            );
+        }
+    }
+}
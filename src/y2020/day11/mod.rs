@@ -0,0 +1,268 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{
+    automaton::Dense,
+    grid::{Grid, Position, Wrap, ADJACENT},
+    observer::{Ignore, Observer},
+    random::Rng,
+    solver::Solver,
+    text::Text,
+    Result,
+};
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub enum Seat {
+    Empty,
+    Occupied,
+}
+
+/// The seats of the waiting area, with `None` for floor.
+pub type SeatMap = Grid<Option<Seat>>;
+
+fn parse_seat_map(input: &str) -> Result<SeatMap> {
+    Grid::parse(input, "one of `L#.`", |c| match c {
+        'L' => Some(Some(Seat::Empty)),
+        '#' => Some(Some(Seat::Occupied)),
+        '.' => Some(None),
+        _ => None,
+    })
+}
+
+fn adjacent_seats(seats: &SeatMap, pos: Position) -> Vec<Position> {
+    seats
+        .neighbors(pos, &ADJACENT, Wrap::None)
+        .filter(|(_, seat)| seat.is_some())
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+fn visible_seats(seats: &SeatMap, pos: Position) -> Vec<Position> {
+    ADJACENT
+        .iter()
+        .filter_map(|offset| {
+            seats
+                .ray(pos, *offset, Wrap::None)
+                .find(|(_, seat)| seat.is_some())
+                .map(|(pos, _)| pos)
+        })
+        .collect()
+}
+
+fn stable_occupied_seat_count(
+    seats: &SeatMap,
+    neighbors: impl Fn(&SeatMap, Position) -> Vec<Position>,
+    tolerance: usize,
+    observer: &mut impl Observer<SeatMap>,
+) -> Result<usize> {
+    let is_occupied = |seat: &Option<Seat>| matches!(seat, Some(Seat::Occupied));
+
+    let mut automaton = Dense::new(seats.iter().map(|(pos, seat)| (pos, *seat)), |pos| {
+        neighbors(seats, pos)
+    });
+    // Actual layouts stabilize within about a hundred rounds, far fewer than they have cells
+    automaton.run_until_stable(
+        seats.width() * seats.height(),
+        is_occupied,
+        |seat, occupied_count| match seat {
+            Some(Seat::Empty) if occupied_count == 0 => Some(Seat::Occupied),
+            Some(Seat::Occupied) if occupied_count >= tolerance => Some(Seat::Empty),
+            seat => *seat,
+        },
+        // The cells are kept in the row-major order of the grid
+        &mut |automaton: &Dense<Position, Option<Seat>>| {
+            let cells = automaton.values().copied().collect();
+            observer.observe(&Grid::new(seats.width(), seats.height(), cells))
+        },
+    )?;
+
+    Ok(automaton.values().filter(|seat| is_occupied(seat)).count())
+}
+
+fn stable_occupied_seat_count_adjacent(
+    seats: &SeatMap,
+    observer: &mut impl Observer<SeatMap>,
+) -> Result<usize> {
+    stable_occupied_seat_count(seats, adjacent_seats, 4, observer)
+}
+
+fn stable_occupied_seat_count_visible(
+    seats: &SeatMap,
+    observer: &mut impl Observer<SeatMap>,
+) -> Result<usize> {
+    stable_occupied_seat_count(seats, visible_seats, 5, observer)
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    part1_observed(input, &mut Ignore)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    part2_observed(input, &mut Ignore)
+}
+
+/// Like [`part1`], but passes the seats to `observer` after each round in which they changed.
+pub fn part1_observed(input: &str, observer: &mut impl Observer<SeatMap>) -> Result<usize> {
+    stable_occupied_seat_count_adjacent(&Solution::parse(&Text::new(input))?, observer)
+}
+
+/// Like [`part2`], but passes the seats to `observer` after each round in which they changed.
+pub fn part2_observed(input: &str, observer: &mut impl Observer<SeatMap>) -> Result<usize> {
+    stable_occupied_seat_count_visible(&Solution::parse(&Text::new(input))?, observer)
+}
+
+/// Generates a seat layout of random size, with some floor and some occupied seats.
+pub fn generate(rng: &mut Rng) -> String {
+    let width = rng.range(1..30);
+    let height = rng.range(1..30);
+
+    Grid::from_fn(width, height, |_| {
+        *rng.choose(&['L', 'L', 'L', 'L', '.', '#'])
+    })
+    .to_string()
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = SeatMap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<'a>(input: &'a Text) -> Result<Self::Input<'a>> {
+        parse_seat_map(input)
+    }
+
+    fn part1(seats: &Self::Input<'_>) -> Result<Self::Answer1> {
+        stable_occupied_seat_count_adjacent(seats, &mut Ignore)
+    }
+
+    fn part2(seats: &Self::Input<'_>) -> Option<Result<Self::Answer2>> {
+        Some(stable_occupied_seat_count_visible(seats, &mut Ignore))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::resaved;
+    use crate::{observer::Trace, Error};
+    use std::ops::ControlFlow;
+
+    static TEST_INPUT: &str = include_str!("test_input.txt");
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(part1(TEST_INPUT), Ok(37));
+    }
+
+    #[test]
+    fn part2_works() {
+        assert_eq!(part2(TEST_INPUT), Ok(26));
+    }
+
+    #[test]
+    fn resaved_input_works() {
+        for input in &resaved(TEST_INPUT) {
+            assert_eq!(part1(input), part1(TEST_INPUT));
+            assert_eq!(part2(input), part2(TEST_INPUT));
+        }
+    }
+
+    #[test]
+    fn invalid_input_is_reported() {
+        assert_eq!(
+            part1("L.L\nLxL\n"),
+            Err(Error::parse(2, 2, "Expected one of `L#.`"))
+        );
+        assert_eq!(
+            part1("L.L\nL.\n"),
+            Err(Error::parse(2, 1, "Expected a row of 3 cells"))
+        );
+    }
+
+    #[test]
+    fn observer_sees_each_round() {
+        let occupied_count = |seats: &SeatMap| {
+            let occupied = seats.values().filter(|seat| **seat == Some(Seat::Occupied));
+            occupied.count()
+        };
+
+        let mut trace = Trace::new();
+        assert_eq!(part1_observed(TEST_INPUT, &mut trace), Ok(37));
+        assert_eq!(
+            trace.states.iter().map(occupied_count).collect::<Vec<_>>(),
+            [71, 20, 51, 30, 37]
+        );
+
+        let mut rounds = 0;
+        let mut stop_after_2 = |_: &SeatMap| {
+            rounds += 1;
+            if rounds < 2 {
+                ControlFlow::Continue(())
+            } else {
+                ControlFlow::Break(())
+            }
+        };
+        assert_eq!(
+            part2_observed(TEST_INPUT, &mut stop_after_2),
+            Err(Error::Stopped)
+        );
+        assert_eq!(rounds, 2);
+    }
+
+    fn reference_occupied(input: &str, visible: bool, tolerance: usize) -> usize {
+        let mut seats: Vec<Vec<u8>> = input.lines().map(|line| line.bytes().collect()).collect();
+        let (height, width) = (seats.len() as isize, seats[0].len() as isize);
+
+        loop {
+            let mut next = seats.clone();
+
+            for y in 0..height {
+                for x in 0..width {
+                    let mut occupied = 0;
+                    for (dx, dy) in ADJACENT {
+                        let (mut nx, mut ny) = (x + dx, y + dy);
+                        while (0..width).contains(&nx) && (0..height).contains(&ny) {
+                            match seats[ny as usize][nx as usize] {
+                                b'#' => occupied += 1,
+                                b'.' if visible => {
+                                    nx += dx;
+                                    ny += dy;
+                                    continue;
+                                }
+                                _ => {}
+                            }
+                            break;
+                        }
+                    }
+
+                    let seat = &mut next[y as usize][x as usize];
+                    match *seat {
+                        b'L' if occupied == 0 => *seat = b'#',
+                        b'#' if occupied >= tolerance => *seat = b'L',
+                        _ => {}
+                    }
+                }
+            }
+
+            if next == seats {
+                break seats.iter().flatten().filter(|seat| **seat == b'#').count();
+            }
+            seats = next;
+        }
+    }
+
+    #[test]
+    fn random_inputs_match_reference() {
+        for seed in 0..30 {
+            let input = generate(&mut Rng::new(seed));
+            let expected1 = reference_occupied(&input, false, 4);
+            let expected2 = reference_occupied(&input, true, 5);
+            assert_eq!(part1(&input), Ok(expected1), "seed {seed}");
+            assert_eq!(part2(&input), Ok(expected2), "seed {seed}");
+        }
+    }
+}
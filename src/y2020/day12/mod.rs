@@ -0,0 +1,227 @@
+use alloc::{format, string::String, vec::Vec};
+
+use itertools::Itertools;
+
+use crate::{
+    error::parse_at,
+    grid::{Offset, Rotation},
+    random::Rng,
+    solver::Solver,
+    text::Text,
+    Error, Result,
+};
+
+type Instruction = (char, i32);
+
+fn instructions(input: &str) -> impl Iterator<Item = Result<Instruction>> + '_ {
+    input.lines().map(move |line| match line.chars().next() {
+        Some(c @ ('N' | 'S' | 'E' | 'W' | 'F')) => Ok((c, parse_at(input, &line[1..])?)),
+        Some(c @ ('L' | 'R')) => {
+            let degrees = parse_at(input, &line[1..])?;
+            if Rotation::from_degrees(degrees).is_none() {
+                return Err(Error::at(
+                    input,
+                    &line[1..],
+                    "Expected a multiple of 90 degrees",
+                ));
+            }
+            Ok((c, degrees))
+        }
+        _ => Err(Error::at(input, line, "Expected one of `NSEWLRF`")),
+    })
+}
+
+/// Moves `count` times by `(dx, dy)`, failing if a coordinate leaves `-isize::MAX..=isize::MAX`,
+/// which keeps them safe to negate when turning.
+fn step((x, y): Offset, (dx, dy): Offset, count: i32) -> Result<Offset> {
+    let count = count as isize;
+    let coordinate = |c: isize, dc: isize| {
+        dc.checked_mul(count)
+            .and_then(|distance| c.checked_add(distance))
+            .filter(|c| *c != isize::MIN)
+            .ok_or(Error::NoSolution)
+    };
+    Ok((coordinate(x, dx)?, coordinate(y, dy)?))
+}
+
+fn turn(c: char, degrees: i32, direction: Offset) -> Offset {
+    let degrees = if c == 'L' { -degrees } else { degrees };
+    Rotation::from_degrees(degrees)
+        .expect("Validated when parsing")
+        .rotate(direction)
+}
+
+fn manhattan_distance((x, y): Offset) -> Result<isize> {
+    x.abs().checked_add(y.abs()).ok_or(Error::NoSolution)
+}
+
+fn navigate_ship(instructions: &[Instruction]) -> Result<isize> {
+    let mut position = (0, 0);
+    let mut direction = (1, 0);
+
+    for &(c, val) in instructions {
+        match c {
+            'N' => position = step(position, (0, -1), val)?,
+            'S' => position = step(position, (0, 1), val)?,
+            'E' => position = step(position, (1, 0), val)?,
+            'W' => position = step(position, (-1, 0), val)?,
+            'L' | 'R' => direction = turn(c, val, direction),
+            'F' => position = step(position, direction, val)?,
+            _ => {}
+        }
+    }
+
+    manhattan_distance(position)
+}
+
+fn navigate_waypoint(instructions: &[Instruction]) -> Result<isize> {
+    let mut position = (0, 0);
+    let mut direction = (10, -1);
+
+    for &(c, val) in instructions {
+        match c {
+            'N' => direction = step(direction, (0, -1), val)?,
+            'S' => direction = step(direction, (0, 1), val)?,
+            'E' => direction = step(direction, (1, 0), val)?,
+            'W' => direction = step(direction, (-1, 0), val)?,
+            'L' | 'R' => direction = turn(c, val, direction),
+            'F' => position = step(position, direction, val)?,
+            _ => {}
+        }
+    }
+
+    manhattan_distance(position)
+}
+
+pub fn part1(input: &str) -> Result<isize> {
+    navigate_ship(&Solution::parse(&Text::new(input))?)
+}
+
+pub fn part2(input: &str) -> Result<isize> {
+    navigate_waypoint(&Solution::parse(&Text::new(input))?)
+}
+
+/// Generates navigation instructions, turning only by multiples of 90 degrees.
+pub fn generate(rng: &mut Rng) -> String {
+    (0..rng.range(1..200))
+        .map(
+            |_| match *rng.choose(&['N', 'S', 'E', 'W', 'L', 'R', 'F', 'F']) {
+                c @ ('L' | 'R') => format!("{c}{}", rng.choose(&[90, 180, 270])),
+                c => format!("{c}{}", rng.range(1..100)),
+            },
+        )
+        .join("\n")
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Vec<Instruction>;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse<'a>(input: &'a Text) -> Result<Self::Input<'a>> {
+        instructions(input).collect()
+    }
+
+    fn part1(instructions: &Self::Input<'_>) -> Result<Self::Answer1> {
+        navigate_ship(instructions)
+    }
+
+    fn part2(instructions: &Self::Input<'_>) -> Option<Result<Self::Answer2>> {
+        Some(navigate_waypoint(instructions))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::resaved;
+
+    static TEST_INPUT: &str = include_str!("test_input.txt");
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(part1(TEST_INPUT), Ok(25));
+    }
+
+    #[test]
+    fn part2_works() {
+        assert_eq!(part2(TEST_INPUT), Ok(286));
+    }
+
+    #[test]
+    fn resaved_input_works() {
+        for input in &resaved(TEST_INPUT) {
+            assert_eq!(part1(input), part1(TEST_INPUT));
+            assert_eq!(part2(input), part2(TEST_INPUT));
+        }
+    }
+
+    #[test]
+    fn invalid_input_is_reported() {
+        assert_eq!(
+            part1("F10\nL45\n"),
+            Err(Error::parse(2, 2, "Expected a multiple of 90 degrees"))
+        );
+        assert_eq!(
+            part1("F10\nX1\n"),
+            Err(Error::parse(2, 1, "Expected one of `NSEWLRF`"))
+        );
+    }
+
+    #[test]
+    fn distances_that_overflow_have_no_solution() {
+        let input = "N2147483647\n".repeat(4) + &"F2147483647\n".repeat(5);
+        assert_eq!(part2(&input), Err(Error::NoSolution));
+    }
+
+    /// Navigates with north and counterclockwise as positive, moving either the ship or its
+    /// waypoint.
+    fn reference_distance(input: &str, waypoint: bool) -> isize {
+        let mut ship = (0, 0);
+        let mut direction = if waypoint { (10, 1) } else { (1, 0) };
+
+        for line in input.lines() {
+            let value: isize = line[1..].parse().unwrap();
+            let moved = if waypoint { &mut direction } else { &mut ship };
+
+            match &line[..1] {
+                "N" => moved.1 += value,
+                "S" => moved.1 -= value,
+                "E" => moved.0 += value,
+                "W" => moved.0 -= value,
+                "F" => ship = (ship.0 + direction.0 * value, ship.1 + direction.1 * value),
+                turn => {
+                    let quarters = if turn == "L" {
+                        value / 90
+                    } else {
+                        4 - value / 90
+                    };
+                    for _ in 0..quarters {
+                        direction = (-direction.1, direction.0);
+                    }
+                }
+            }
+        }
+
+        ship.0.abs() + ship.1.abs()
+    }
+
+    #[test]
+    fn random_inputs_match_reference() {
+        for seed in 0..100 {
+            let input = generate(&mut Rng::new(seed));
+            assert_eq!(
+                part1(&input),
+                Ok(reference_distance(&input, false)),
+                "seed {seed}"
+            );
+            assert_eq!(
+                part2(&input),
+                Ok(reference_distance(&input, true)),
+                "seed {seed}"
+            );
+        }
+    }
+}
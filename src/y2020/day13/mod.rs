@@ -0,0 +1,244 @@
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    error::parse_at,
+    modular::{crt, gcd},
+    random::Rng,
+    solver::Solver,
+    text::Text,
+    Error, Result,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Notes {
+    pub departure_timestamp: u64,
+    /// The IDs of the buses in service, which must be positive.
+    pub bus_ids: Box<[Option<u64>]>,
+}
+
+impl Notes {
+    fn parse(input: &str) -> Result<Self> {
+        let mut lines = input.lines();
+        let first_line = lines
+            .next()
+            .ok_or_else(|| Error::at_end(input, "Expected a departure timestamp"))?;
+        let second_line = lines
+            .next()
+            .ok_or_else(|| Error::at_end(input, "Expected a list of bus IDs"))?;
+
+        let bus_ids = second_line
+            .split(',')
+            .map(|s| match s {
+                "x" => Ok(None),
+                _ => match parse_at(input, s)? {
+                    0 => Err(Error::at(input, s, "Bus IDs must be positive")),
+                    bus_id => Ok(Some(bus_id)),
+                },
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            departure_timestamp: parse_at(input, first_line)?,
+            bus_ids,
+        })
+    }
+
+    /// Notes that were deserialized rather than parsed may have a bus ID of zero, for which there
+    /// is no solution.
+    fn check_bus_ids(&self) -> Result<()> {
+        if self.bus_ids.contains(&Some(0)) {
+            Err(Error::NoSolution)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+fn earliest_bus(notes: &Notes) -> Result<u64> {
+    notes.check_bus_ids()?;
+    notes
+        .bus_ids
+        .iter()
+        .flatten()
+        .map(|bus_id| {
+            let wait = (bus_id - notes.departure_timestamp % bus_id) % bus_id;
+            (wait, bus_id)
+        })
+        .min()
+        .and_then(|(wait, bus_id)| wait.checked_mul(*bus_id))
+        .ok_or(Error::NoSolution)
+}
+
+fn earliest_consecutive_departures(notes: &Notes) -> Result<u64> {
+    notes.check_bus_ids()?;
+
+    // The bus with offset `offset` departs at the timestamp plus `offset`
+    let congruences = notes
+        .bus_ids
+        .iter()
+        .zip(0u64..)
+        .filter_map(|(bus_id, offset)| {
+            let bus_id = (*bus_id)?;
+            Some(((bus_id - offset % bus_id) % bus_id, bus_id))
+        });
+
+    let (timestamp, _) = crt(congruences).ok_or(Error::NoSolution)?;
+    Ok(timestamp)
+}
+
+pub fn part1(input: &str) -> Result<u64> {
+    earliest_bus(&Solution::parse(&Text::new(input))?)
+}
+
+pub fn part2(input: &str) -> Result<u64> {
+    earliest_consecutive_departures(&Solution::parse(&Text::new(input))?)
+}
+
+/// Generates a schedule of pairwise coprime bus IDs, starting with a bus.
+pub fn generate(rng: &mut Rng) -> String {
+    let mut bus_ids: Vec<u64> = Vec::new();
+
+    // Keeps the earliest consecutive departures small enough to brute-force
+    while bus_ids.len() < 2 || bus_ids.iter().product::<u64>() < 100_000 {
+        let bus_id = rng.range(2..50) as u64;
+        if bus_ids.iter().all(|other| gcd(*other, bus_id) == 1) {
+            bus_ids.push(bus_id);
+        }
+    }
+    bus_ids.pop();
+
+    let mut schedule = vec![bus_ids[0].to_string()];
+    for bus_id in &bus_ids[1..] {
+        for _ in 0..rng.range(0..4) {
+            schedule.push("x".into());
+        }
+        schedule.push(bus_id.to_string());
+    }
+
+    format!("{}\n{}", rng.range(0..1_000_000), schedule.join(","))
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Notes;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse<'a>(input: &'a Text) -> Result<Self::Input<'a>> {
+        Notes::parse(input)
+    }
+
+    fn part1(notes: &Self::Input<'_>) -> Result<Self::Answer1> {
+        earliest_bus(notes)
+    }
+
+    fn part2(notes: &Self::Input<'_>) -> Option<Result<Self::Answer2>> {
+        Some(earliest_consecutive_departures(notes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::resaved;
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(part1("939\n7,13,x,x,59,x,31,19"), Ok(295));
+    }
+
+    #[test]
+    fn part2_works() {
+        assert_eq!(part2("0\n17,x,13,19"), Ok(3417));
+        assert_eq!(part2("0\n67,7,59,61"), Ok(754_018));
+        assert_eq!(part2("0\n67,x,7,59,61"), Ok(779_210));
+        assert_eq!(part2("0\n67,7,x,59,61"), Ok(1_261_476));
+        assert_eq!(part2("0\n1789,37,47,1889"), Ok(1_202_161_486));
+    }
+
+    #[test]
+    fn resaved_input_works() {
+        for input in &resaved("939\n7,13,x,x,59,x,31,19") {
+            assert_eq!(part1(input), part1("939\n7,13,x,x,59,x,31,19"));
+            assert_eq!(part2(input), part2("939\n7,13,x,x,59,x,31,19"));
+        }
+    }
+
+    #[test]
+    fn invalid_input_is_reported() {
+        assert_eq!(
+            part1("939"),
+            Err(Error::parse(2, 1, "Expected a list of bus IDs"))
+        );
+        assert_eq!(
+            part1("939\n7,0"),
+            Err(Error::parse(2, 3, "Bus IDs must be positive"))
+        );
+        assert_eq!(part1("939\nx,x"), Err(Error::NoSolution));
+        assert_eq!(part2("0\n2,4"), Err(Error::NoSolution));
+        assert_eq!(part2("0\n6,x,4"), Ok(6));
+        assert_eq!(
+            part2("0\n18446744073709551615,18446744073709551614"),
+            Err(Error::NoSolution)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn notes_round_trip_through_json() {
+        let notes = Solution::parse(&Text::new("939\n7,13,x,x,59,x,31,19")).unwrap();
+        let json = serde_json::to_string(&notes).unwrap();
+        assert_eq!(
+            json,
+            r#"{"departure_timestamp":939,"bus_ids":[7,13,null,null,59,null,31,19]}"#
+        );
+        assert_eq!(serde_json::from_str::<Notes>(&json).unwrap(), notes);
+
+        let notes = serde_json::from_str(r#"{"departure_timestamp":939,"bus_ids":[7,0]}"#).unwrap();
+        assert_eq!(Solution::part1(&notes), Err(Error::NoSolution));
+        assert_eq!(Solution::part2(&notes), Some(Err(Error::NoSolution)));
+    }
+
+    #[test]
+    fn random_inputs_match_reference() {
+        for seed in 0..100 {
+            let input = generate(&mut Rng::new(seed));
+            let (timestamp, schedule) = input.split_once('\n').unwrap();
+            let timestamp: u64 = timestamp.parse().unwrap();
+            let buses: Vec<(u64, u64)> = schedule
+                .split(',')
+                .zip(0..)
+                .filter_map(|(bus_id, offset)| Some((bus_id.parse().ok()?, offset)))
+                .collect();
+
+            let mut bus_ids: Vec<u64> = buses.iter().map(|(bus_id, _)| *bus_id).collect();
+            bus_ids.sort_unstable();
+            let earliest_bus = (timestamp..)
+                .find_map(|t| {
+                    let bus_id = bus_ids.iter().find(|bus_id| t % *bus_id == 0)?;
+                    Some((t - timestamp) * bus_id)
+                })
+                .unwrap();
+
+            let earliest_departures = (0..)
+                .step_by(buses[0].0 as usize)
+                .find(|t| {
+                    buses
+                        .iter()
+                        .all(|(bus_id, offset)| (t + offset) % bus_id == 0)
+                })
+                .unwrap();
+
+            assert_eq!(part1(&input), Ok(earliest_bus), "seed {seed}");
+            assert_eq!(part2(&input), Ok(earliest_departures), "seed {seed}");
+        }
+    }
+}
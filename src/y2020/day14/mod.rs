@@ -0,0 +1,377 @@
+use alloc::{format, string::String, vec::Vec};
+
+use crate::{
+    collections::HashMap, parser::Parser, random::Rng, solver::Solver, text::Text, Error, Result,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BitMask {
+    On,
+    Off,
+    Floating,
+}
+
+const MASK_SIZE: usize = 36;
+/// The bits of a mask, least significant first.
+pub type Mask = [BitMask; MASK_SIZE];
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Program {
+    #[cfg_attr(feature = "serde", serde(with = "mask_string"))]
+    pub mask: Mask,
+    /// The addresses and values written by the program.
+    pub memory_init: Vec<(u64, u64)>,
+}
+
+fn parse_mask(p: &mut Parser) -> Result<Mask> {
+    let start = *p;
+    let bits = p.take_while(|c| matches!(c, '0' | '1' | 'X'));
+    if bits.len() != MASK_SIZE {
+        return Err(start.error(format!("Expected a mask of {MASK_SIZE} `0`, `1` or `X`")));
+    }
+
+    let mut mask = [BitMask::Off; MASK_SIZE];
+    for (i, c) in bits.chars().rev().enumerate() {
+        mask[i] = match c {
+            '0' => BitMask::Off,
+            '1' => BitMask::On,
+            _ => BitMask::Floating,
+        }
+    }
+
+    Ok(mask)
+}
+
+/// Writes masks as they are in the input, most significant bit first.
+#[cfg(feature = "serde")]
+mod mask_string {
+    use alloc::string::String;
+
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    use super::{parse_mask, BitMask, Mask};
+    use crate::parser::Parser;
+
+    pub fn serialize<S: Serializer>(mask: &Mask, serializer: S) -> Result<S::Ok, S::Error> {
+        let bits = mask
+            .iter()
+            .rev()
+            .map(|bit| match bit {
+                BitMask::Off => '0',
+                BitMask::On => '1',
+                BitMask::Floating => 'X',
+            })
+            .collect::<String>();
+        serializer.serialize_str(&bits)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Mask, D::Error> {
+        let bits = String::deserialize(deserializer)?;
+        let mut p = Parser::new(&bits);
+        let mask = parse_mask(&mut p).map_err(de::Error::custom)?;
+        p.end().map_err(de::Error::custom)?;
+        Ok(mask)
+    }
+}
+
+fn parse_programs(input: &str) -> Result<Vec<Program>> {
+    let mut programs: Vec<Program> = Vec::new();
+
+    Parser::new(input).lines(|p| {
+        let line = *p;
+        if p.eat("mask = ") {
+            programs.push(Program {
+                mask: parse_mask(p)?,
+                memory_init: Vec::new(),
+            });
+        } else if p.eat("mem[") {
+            let program = programs
+                .last_mut()
+                .ok_or_else(|| line.error("Expected a mask before the first write"))?;
+            let index = p.number()?;
+            p.tag("] = ")?;
+            let value = p.number()?;
+            program.memory_init.push((index, value));
+        } else {
+            return Err(p.error("Expected `mask = <mask>` or `mem[<index>] = <value>`"));
+        }
+        Ok(())
+    })?;
+
+    Ok(programs)
+}
+
+fn apply_mask(mask: Mask, value: u64) -> u64 {
+    mask.iter()
+        .enumerate()
+        .fold(value, |value, (i, mask)| match mask {
+            BitMask::On => value | 1 << i,
+            BitMask::Off => value & !(1 << i),
+            BitMask::Floating => value,
+        })
+}
+
+fn checked_sum(values: impl IntoIterator<Item = Option<u64>>) -> Result<u64> {
+    values
+        .into_iter()
+        .try_fold(0u64, |sum, value| sum.checked_add(value?))
+        .ok_or(Error::NoSolution)
+}
+
+fn sum_masked_values(programs: &[Program]) -> Result<u64> {
+    let mut memory = HashMap::default();
+
+    for program in programs {
+        for (address, value) in program.memory_init.iter().copied() {
+            let masked_value = apply_mask(program.mask, value);
+            memory.insert(address, masked_value);
+        }
+    }
+
+    checked_sum(memory.values().copied().map(Some))
+}
+
+/// The addresses that are `address` outside of the `floating` bits, which are zero in `address`.
+#[derive(Debug, Clone, Copy)]
+struct Addresses {
+    address: u64,
+    floating: u64,
+}
+
+impl Addresses {
+    fn new(mask: Mask, address: u64) -> Self {
+        let mut addresses = Addresses {
+            address,
+            floating: 0,
+        };
+        for (i, bit) in mask.iter().enumerate() {
+            match bit {
+                BitMask::On => addresses.address |= 1 << i,
+                BitMask::Off => {}
+                BitMask::Floating => {
+                    addresses.address &= !(1 << i);
+                    addresses.floating |= 1 << i;
+                }
+            }
+        }
+        addresses
+    }
+
+    fn count(self) -> u64 {
+        1 << self.floating.count_ones()
+    }
+
+    /// Passes the addresses that are not in `other` to `push`, as disjoint sets.
+    fn remove(self, other: Addresses, mut push: impl FnMut(Addresses)) {
+        let fixed = !(self.floating | other.floating);
+        if (self.address ^ other.address) & fixed != 0 {
+            push(self);
+            return;
+        }
+
+        // Fixes the bits that only float here one at a time, splitting off the addresses where
+        // the bit differs from `other`. What is left at the end is in `other`.
+        let mut rest = self;
+        let mut bits = self.floating & !other.floating;
+        while bits != 0 {
+            let bit = bits & bits.wrapping_neg();
+            bits &= !bit;
+
+            rest.floating &= !bit;
+            push(Addresses {
+                address: rest.address | (!other.address & bit),
+                floating: rest.floating,
+            });
+            rest.address |= other.address & bit;
+        }
+    }
+}
+
+fn sum_values_at_masked_addresses(programs: &[Program]) -> Result<u64> {
+    // Disjoint sets of addresses, with the value last written to each
+    let mut memory: Vec<(Addresses, u64)> = Vec::new();
+
+    for program in programs {
+        for (address, value) in program.memory_init.iter().copied() {
+            let written = Addresses::new(program.mask, address);
+
+            let mut next_memory = Vec::with_capacity(memory.len() + 1);
+            for (addresses, value) in memory {
+                addresses.remove(written, |rest| next_memory.push((rest, value)));
+            }
+            next_memory.push((written, value));
+            memory = next_memory;
+        }
+    }
+
+    checked_sum(
+        memory
+            .iter()
+            .map(|(addresses, value)| value.checked_mul(addresses.count())),
+    )
+}
+
+pub fn part1(input: &str) -> Result<u64> {
+    sum_masked_values(&Solution::parse(&Text::new(input))?)
+}
+
+pub fn part2(input: &str) -> Result<u64> {
+    sum_values_at_masked_addresses(&Solution::parse(&Text::new(input))?)
+}
+
+/// Generates masks with few floating bits, so that part 2 writes to a bounded number of addresses.
+pub fn generate(rng: &mut Rng) -> String {
+    let mut lines = Vec::new();
+
+    for _ in 0..rng.range(1..10) {
+        let mut mask: Vec<char> = (0..MASK_SIZE).map(|_| *rng.choose(&['0', '1'])).collect();
+        for _ in 0..rng.range(0..6) {
+            mask[rng.range(0..MASK_SIZE)] = 'X';
+        }
+        lines.push(format!("mask = {}", mask.into_iter().collect::<String>()));
+
+        for _ in 0..rng.range(1..6) {
+            let address = rng.range(0..1 << 16);
+            let value = rng.next_u64() >> (64 - MASK_SIZE);
+            lines.push(format!("mem[{address}] = {value}"));
+        }
+    }
+
+    lines.join("\n")
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Vec<Program>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse<'a>(input: &'a Text) -> Result<Self::Input<'a>> {
+        parse_programs(input)
+    }
+
+    fn part1(programs: &Self::Input<'_>) -> Result<Self::Answer1> {
+        sum_masked_values(programs)
+    }
+
+    fn part2(programs: &Self::Input<'_>) -> Option<Result<Self::Answer2>> {
+        Some(sum_values_at_masked_addresses(programs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::resaved;
+    use crate::Error;
+
+    static TEST_INPUT_0: &str = include_str!("test_input_0.txt");
+    static TEST_INPUT_1: &str = include_str!("test_input_1.txt");
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(part1(TEST_INPUT_0), Ok(165));
+    }
+
+    #[test]
+    fn part2_works() {
+        assert_eq!(part2(TEST_INPUT_1), Ok(208));
+
+        let floating = "X".repeat(MASK_SIZE);
+        assert_eq!(
+            part2(&format!("mask = {floating}\nmem[0] = 3\nmem[8] = 1")),
+            Ok(1 << MASK_SIZE)
+        );
+    }
+
+    #[test]
+    fn resaved_input_works() {
+        for input in &resaved(TEST_INPUT_1) {
+            assert_eq!(part1(input), part1(TEST_INPUT_1));
+            assert_eq!(part2(input), part2(TEST_INPUT_1));
+        }
+    }
+
+    #[test]
+    fn invalid_input_is_reported() {
+        assert_eq!(
+            part1("mem[8] = 11"),
+            Err(Error::parse(1, 1, "Expected a mask before the first write"))
+        );
+        assert_eq!(
+            part1("mask = XX1X0\nmem[8] = 11"),
+            Err(Error::parse(1, 8, "Expected a mask of 36 `0`, `1` or `X`"))
+        );
+        assert_eq!(
+            part1("mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X\nmem[8] == 11"),
+            Err(Error::parse(2, 6, "Expected `] = `"))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn programs_round_trip_through_json() {
+        let programs = Solution::parse(&Text::new(TEST_INPUT_1)).unwrap();
+        let json = serde_json::to_string(&programs).unwrap();
+        assert_eq!(
+            json,
+            r#"[{"mask":"000000000000000000000000000000X1001X","memory_init":[[42,100]]},{"mask":"00000000000000000000000000000000X0XX","memory_init":[[26,1]]}]"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Vec<Program>>(&json).unwrap(),
+            programs
+        );
+
+        assert!(
+            serde_json::from_str::<Vec<Program>>(r#"[{"mask":"X1","memory_init":[]}]"#)
+                .unwrap_err()
+                .to_string()
+                .contains("Expected a mask of 36")
+        );
+    }
+
+    fn reference_sums(input: &str) -> (u64, u64) {
+        let mut memory1 = HashMap::default();
+        let mut memory2 = HashMap::default();
+        let (mut ones, mut floating) = (0, 0);
+
+        for line in input.lines() {
+            if let Some(mask) = line.strip_prefix("mask = ") {
+                ones = u64::from_str_radix(&mask.replace('X', "0"), 2).unwrap();
+                floating =
+                    u64::from_str_radix(&mask.replace('1', "0").replace('X', "1"), 2).unwrap();
+            } else {
+                let (address, value) = line["mem[".len()..].split_once("] = ").unwrap();
+                let (address, value): (u64, u64) =
+                    (address.parse().unwrap(), value.parse().unwrap());
+
+                memory1.insert(address, value & floating | ones);
+
+                // Visits every subset of the floating bits
+                let base = (address | ones) & !floating;
+                let mut subset = floating;
+                loop {
+                    memory2.insert(base | subset, value);
+                    if subset == 0 {
+                        break;
+                    }
+                    subset = (subset - 1) & floating;
+                }
+            }
+        }
+
+        (memory1.values().sum(), memory2.values().sum())
+    }
+
+    #[test]
+    fn random_inputs_match_reference() {
+        for seed in 0..100 {
+            let input = generate(&mut Rng::new(seed));
+            let (expected1, expected2) = reference_sums(&input);
+            assert_eq!(part1(&input), Ok(expected1), "seed {seed}");
+            assert_eq!(part2(&input), Ok(expected2), "seed {seed}");
+        }
+    }
+}
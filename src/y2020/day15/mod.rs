@@ -0,0 +1,204 @@
+use alloc::{string::String, vec, vec::Vec};
+use core::mem;
+
+use itertools::Itertools;
+
+use crate::{error::parse_at, random::Rng, solver::Solver, text::Text, Error, Result};
+
+/// Puzzle constants, defaulting to those of the actual puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    /// The turn whose number is spoken in part 1.
+    pub part1_turns: usize,
+    /// The turn whose number is spoken in part 2.
+    pub part2_turns: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            part1_turns: 2020,
+            part2_turns: 30_000_000,
+        }
+    }
+}
+
+/// Returns the number spoken on the given 1-based turn.
+fn get_spoken_number(initial_numbers: &[usize], turn: usize) -> Result<usize> {
+    let index = turn.checked_sub(1).ok_or(Error::NoSolution)?;
+    Ok(get_number(initial_numbers, index))
+}
+
+fn get_number(initial_numbers: &[usize], index: usize) -> usize {
+    if let Some(value) = initial_numbers.get(index) {
+        return *value;
+    }
+
+    // Every number spoken after the starting ones is an age, so less than the turn it is spoken
+    // on, and starting numbers past `index` are never looked up again
+    let mut prev_turns = vec![None; index + 1];
+
+    let (&last, earlier) = initial_numbers
+        .split_last()
+        .expect("there are starting numbers");
+    for (i, val) in earlier.iter().enumerate() {
+        if let Some(prev_turn) = prev_turns.get_mut(*val) {
+            *prev_turn = Some(i);
+        }
+    }
+
+    let mut turn = earlier.len();
+    let mut number = earlier
+        .iter()
+        .rposition(|val| *val == last)
+        .map_or(0, |prev_turn| turn - prev_turn);
+    if let Some(prev_turn) = prev_turns.get_mut(last) {
+        *prev_turn = Some(turn);
+    }
+    turn += 1;
+
+    while turn < index {
+        let prev_turn = mem::replace(&mut prev_turns[number], Some(turn));
+        number = prev_turn.map_or(0, |prev_turn| turn - prev_turn);
+        turn += 1;
+    }
+
+    number
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    part1_with(input, &Params::default())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    part2_with(input, &Params::default())
+}
+
+pub fn part1_with(input: &str, params: &Params) -> Result<usize> {
+    get_spoken_number(&Solution::parse(&Text::new(input))?, params.part1_turns)
+}
+
+pub fn part2_with(input: &str, params: &Params) -> Result<usize> {
+    get_spoken_number(&Solution::parse(&Text::new(input))?, params.part2_turns)
+}
+
+/// Generates a few distinct starting numbers.
+pub fn generate(rng: &mut Rng) -> String {
+    let mut numbers: Vec<usize> = (0..20).collect();
+    rng.shuffle(&mut numbers);
+    numbers.truncate(rng.range(1..8));
+
+    numbers.iter().join(",")
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<'a>(input: &'a Text) -> Result<Self::Input<'a>> {
+        if input.trim().is_empty() {
+            return Err(Error::at_end(input, "Expected starting numbers"));
+        }
+
+        input
+            .trim_end()
+            .split(',')
+            .map(|s| parse_at(input, s))
+            .collect()
+    }
+
+    fn part1(numbers: &Self::Input<'_>) -> Result<Self::Answer1> {
+        get_spoken_number(numbers, Params::default().part1_turns)
+    }
+
+    fn part2(numbers: &Self::Input<'_>) -> Option<Result<Self::Answer2>> {
+        Some(get_spoken_number(numbers, Params::default().part2_turns))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::resaved;
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(part1("0,3,6"), Ok(436));
+    }
+
+    #[test]
+    fn part2_works() {
+        let params = Params {
+            part2_turns: 10,
+            ..Params::default()
+        };
+        assert_eq!(part2_with("0,3,6", &params), Ok(0));
+        assert_eq!(part2_with("3,1,2", &params), Ok(8));
+    }
+
+    #[test]
+    fn repeated_and_large_starting_numbers_work() {
+        let params = Params {
+            part1_turns: 4,
+            part2_turns: 5,
+        };
+        assert_eq!(part1_with("0,6,6", &params), Ok(1));
+        assert_eq!(part2_with("0,6,6", &params), Ok(0));
+        assert_eq!(part1_with("18446744073709551615", &params), Ok(1));
+        assert_eq!(part1_with("0,0,18446744073709551614", &params), Ok(0));
+        assert_eq!(part2_with("1000000000000,1000000000000", &params), Ok(0));
+    }
+
+    #[test]
+    fn resaved_input_works() {
+        for input in &resaved("0,3,6") {
+            assert_eq!(part1(input), part1("0,3,6"));
+        }
+    }
+
+    #[test]
+    fn params_change_the_turns() {
+        let params = Params {
+            part1_turns: 9,
+            part2_turns: 2020,
+        };
+        assert_eq!(part1_with("0,3,6", &params), Ok(4));
+        assert_eq!(part2_with("0,3,6", &params), Ok(436));
+
+        let params = Params {
+            part1_turns: 0,
+            ..Params::default()
+        };
+        assert_eq!(part1_with("0,3,6", &params), Err(Error::NoSolution));
+    }
+
+    #[test]
+    fn random_inputs_match_reference() {
+        for seed in 0..20 {
+            let input = generate(&mut Rng::new(seed));
+            let mut spoken: Vec<usize> = input.split(',').map(|s| s.parse().unwrap()).collect();
+
+            while spoken.len() < 2020 {
+                let (last, earlier) = spoken.split_last().unwrap();
+                let age = earlier
+                    .iter()
+                    .rev()
+                    .position(|number| number == last)
+                    .map_or(0, |position| position + 1);
+                spoken.push(age);
+            }
+
+            assert_eq!(part1(&input), Ok(spoken[2019]), "seed {seed}");
+
+            let params = Params {
+                part1_turns: 1000,
+                part2_turns: 2020,
+            };
+            assert_eq!(part1_with(&input, &params), Ok(spoken[999]), "seed {seed}");
+            assert_eq!(part2_with(&input, &params), Ok(spoken[2019]), "seed {seed}");
+        }
+    }
+}
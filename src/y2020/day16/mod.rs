@@ -0,0 +1,506 @@
+use alloc::{borrow::Cow, boxed::Box, format, string::String, vec, vec::Vec};
+use core::ops::RangeInclusive;
+
+use itertools::Itertools;
+
+use crate::{
+    bitset::BitSet, graph::Graph, parser::Parser, random::Rng, reduce::Structure, solver::Solver,
+    text::Text, Error, Result,
+};
+
+const DEPARTURE: &str = "departure";
+
+pub type Ticket = Box<[usize]>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rule<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub name: Cow<'a, str>,
+    pub ranges: [RangeInclusive<usize>; 2],
+}
+
+impl Rule<'_> {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn matches(&self, value: usize) -> bool {
+        self.ranges.iter().any(|range| range.contains(&value))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Notes<'a> {
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub rules: Vec<Rule<'a>>,
+    /// The tickets, which all have one value per rule.
+    pub my_ticket: Ticket,
+    pub nearby_tickets: Vec<Ticket>,
+}
+
+fn parse_range(p: &mut Parser) -> Result<RangeInclusive<usize>> {
+    let start = p.number()?;
+    p.tag("-")?;
+    Ok(start..=p.number()?)
+}
+
+fn parse_rule<'a>(p: &mut Parser<'a>) -> Result<Rule<'a>> {
+    let name = p.take_until(": ")?;
+    p.tag(": ")?;
+    let first = parse_range(p)?;
+    p.tag(" or ")?;
+    let second = parse_range(p)?;
+
+    Ok(Rule {
+        name: name.into(),
+        ranges: [first, second],
+    })
+}
+
+fn parse_ticket(p: &mut Parser, len: usize) -> Result<Ticket> {
+    let start = *p;
+    let ticket: Vec<usize> = p.separated(",", Parser::number)?;
+
+    if ticket.len() != len {
+        return Err(start.error(format!("Expected {len} values")));
+    }
+
+    Ok(ticket.into())
+}
+
+impl<'a> Notes<'a> {
+    fn parse(input: &'a str) -> Result<Self> {
+        let mut blocks = Parser::new(input).blocks();
+        let mut next_block = |header, message| -> Result<Parser<'a>> {
+            let mut block = blocks.next().ok_or_else(|| Error::at_end(input, message))?;
+            block.tag(header)?;
+            Ok(block)
+        };
+
+        let rules = next_block("", "Expected the rules")?.lines(parse_rule)?;
+
+        let mut block = next_block("your ticket:\n", "Expected your ticket")?;
+        let my_ticket = parse_ticket(&mut block, rules.len())?;
+        block.eat("\n");
+        block.end()?;
+
+        let nearby_tickets = next_block("nearby tickets:\n", "Expected nearby tickets")?
+            .lines(|p| parse_ticket(p, rules.len()))?;
+
+        Ok(Self {
+            rules,
+            my_ticket,
+            nearby_tickets,
+        })
+    }
+
+    /// Notes that were deserialized rather than parsed may have tickets with the wrong number of
+    /// values, for which there is no solution.
+    fn check_ticket_lengths(&self) -> Result<()> {
+        let len = self.rules.len();
+        if self.my_ticket.len() == len && self.nearby_tickets.iter().all(|t| t.len() == len) {
+            Ok(())
+        } else {
+            Err(Error::NoSolution)
+        }
+    }
+}
+
+fn ticket_scanning_error_rate(notes: &Notes) -> Result<usize> {
+    notes
+        .nearby_tickets
+        .iter()
+        .flat_map(|ticket| ticket.iter().copied())
+        .filter(|value| !notes.rules.iter().any(|rule| rule.matches(*value)))
+        .try_fold(0usize, |sum, value| sum.checked_add(value))
+        .ok_or(Error::NoSolution)
+}
+
+/// Puzzle constants, defaulting to those of the actual puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    /// The prefix of the fields whose values are multiplied in part 2.
+    pub prefix: String,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            prefix: DEPARTURE.into(),
+        }
+    }
+}
+
+fn field_values_product(notes: &Notes, prefix: &str) -> Result<usize> {
+    notes.check_ticket_lengths()?;
+
+    let Notes {
+        rules,
+        my_ticket,
+        nearby_tickets,
+    } = notes;
+
+    let valid_nearby_tickets: Vec<_> = nearby_tickets
+        .iter()
+        .filter(|ticket| {
+            ticket
+                .iter()
+                .all(|value| rules.iter().any(|rule| rule.matches(*value)))
+        })
+        .collect();
+
+    // The rules that all the values at each position match, narrowed down ticket by ticket
+    let mut allowed_rules = vec![BitSet::from_iter(0..rules.len()); my_ticket.len()];
+    for ticket in &valid_nearby_tickets {
+        for (allowed, value) in allowed_rules.iter_mut().zip(ticket.iter()) {
+            let matching: BitSet = (0..rules.len())
+                .filter(|rule_index| rules[*rule_index].matches(*value))
+                .collect();
+            allowed.intersect_with(&matching);
+        }
+    }
+
+    // Positions are linked to their allowed rules, and then given distinct rules. The nodes of the
+    // positions come first, followed by those of the rules.
+    let mut graph = Graph::new(my_ticket.len() + rules.len());
+    for (position, allowed) in allowed_rules.iter().enumerate() {
+        for rule_index in allowed {
+            graph.add_edge(position, my_ticket.len() + rule_index, ());
+        }
+    }
+
+    let position_rules: Vec<usize> = graph
+        .assignment(0..my_ticket.len())
+        .ok_or(Error::NoSolution)?
+        .into_iter()
+        .map(|node| node - my_ticket.len())
+        .collect();
+
+    my_ticket
+        .iter()
+        .enumerate()
+        .map(|(position, value)| (rules[position_rules[position]].name(), *value))
+        .filter_map(|(rule_name, value)| {
+            if rule_name.starts_with(prefix) {
+                Some(value)
+            } else {
+                None
+            }
+        })
+        .try_fold(1usize, |product, value| product.checked_mul(value))
+        .ok_or(Error::NoSolution)
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    ticket_scanning_error_rate(&Solution::parse(&Text::new(input))?)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    part2_with(input, &Params::default())
+}
+
+pub fn part2_with(input: &str, params: &Params) -> Result<usize> {
+    field_values_product(&Solution::parse(&Text::new(input))?, &params.prefix)
+}
+
+/// Counts the ways to assign a distinct allowed rule to each position.
+fn count_rule_assignments(
+    allowed: &[Vec<bool>],
+    assigned: &mut Vec<bool>,
+    position: usize,
+) -> usize {
+    if position == allowed.len() {
+        return 1;
+    }
+
+    let mut count = 0;
+    for rule in 0..assigned.len() {
+        if allowed[position][rule] && !assigned[rule] {
+            assigned[rule] = true;
+            count += count_rule_assignments(allowed, assigned, position + 1);
+            assigned[rule] = false;
+        }
+    }
+    count
+}
+
+/// Generates notes where exactly one assignment of rules to positions fits the valid tickets.
+pub fn generate(rng: &mut Rng) -> String {
+    const NAMES: [&str; 10] = [
+        "departure location",
+        "departure station",
+        "departure time",
+        "arrival platform",
+        "class",
+        "duration",
+        "price",
+        "route",
+        "seat",
+        "zone",
+    ];
+
+    loop {
+        let mut names = NAMES.to_vec();
+        rng.shuffle(&mut names);
+        names.truncate(rng.range(2..7));
+
+        let rules: Vec<[RangeInclusive<usize>; 2]> = names
+            .iter()
+            .map(|_| {
+                let a = rng.range(1..50);
+                let b = a + rng.range(1..30);
+                let c = b + rng.range(2..30);
+                let d = c + rng.range(1..30);
+                [a..=b, c..=d]
+            })
+            .collect();
+        let max_value = rules.iter().map(|[_, high]| *high.end()).max().unwrap();
+
+        let mut rule_positions: Vec<usize> = (0..rules.len()).collect();
+        rng.shuffle(&mut rule_positions);
+
+        let random_ticket = |rng: &mut Rng| -> Vec<usize> {
+            rule_positions
+                .iter()
+                .map(|rule| {
+                    let range = rng.choose(&rules[*rule]).clone();
+                    rng.range(*range.start()..range.end() + 1)
+                })
+                .collect()
+        };
+
+        let my_ticket = random_ticket(rng);
+        let mut valid_tickets = Vec::new();
+        let mut nearby_tickets = Vec::new();
+
+        for _ in 0..rng.range(5..40) {
+            let mut ticket = random_ticket(rng);
+            if rng.chance(0.2) {
+                let position = rng.range(0..ticket.len());
+                ticket[position] = rng.range(max_value + 1..max_value + 100);
+            } else {
+                valid_tickets.push(ticket.clone());
+            }
+            nearby_tickets.push(ticket);
+        }
+
+        let allowed: Vec<Vec<bool>> = (0..rules.len())
+            .map(|position| {
+                rules
+                    .iter()
+                    .map(|ranges| {
+                        valid_tickets.iter().all(|ticket| {
+                            ranges.iter().any(|range| range.contains(&ticket[position]))
+                        })
+                    })
+                    .collect()
+            })
+            .collect();
+
+        if count_rule_assignments(&allowed, &mut vec![false; rules.len()], 0) == 1 {
+            let rules = names.iter().zip(&rules).map(|(name, [low, high])| {
+                format!(
+                    "{name}: {}-{} or {}-{}",
+                    low.start(),
+                    low.end(),
+                    high.start(),
+                    high.end()
+                )
+            });
+            let tickets = |tickets: &[Vec<usize>]| {
+                tickets
+                    .iter()
+                    .map(|ticket| ticket.iter().join(","))
+                    .join("\n")
+            };
+
+            break format!(
+                "{}\n\nyour ticket:\n{}\n\nnearby tickets:\n{}",
+                rules.collect::<Vec<_>>().join("\n"),
+                tickets(&[my_ticket]),
+                tickets(&nearby_tickets)
+            );
+        }
+    }
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Notes<'a>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const STRUCTURE: Structure = Structure::Sections;
+
+    fn parse<'a>(input: &'a Text) -> Result<Self::Input<'a>> {
+        Notes::parse(input)
+    }
+
+    fn part1(notes: &Self::Input<'_>) -> Result<Self::Answer1> {
+        ticket_scanning_error_rate(notes)
+    }
+
+    fn part2(notes: &Self::Input<'_>) -> Option<Result<Self::Answer2>> {
+        Some(field_values_product(notes, DEPARTURE))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::resaved;
+
+    static TEST_INPUT_0: &str = include_str!("test_input_0.txt");
+    static TEST_INPUT_1: &str = include_str!("test_input_1.txt");
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(part1(TEST_INPUT_0), Ok(71));
+    }
+
+    #[test]
+    fn part2_works() {
+        assert_eq!(part2(TEST_INPUT_1), Ok(1));
+    }
+
+    #[test]
+    fn resaved_input_works() {
+        for input in &resaved(TEST_INPUT_1) {
+            assert_eq!(part1(input), part1(TEST_INPUT_1));
+            assert_eq!(part2(input), part2(TEST_INPUT_1));
+        }
+    }
+
+    #[test]
+    fn params_change_the_prefix() {
+        let params = |prefix: &str| Params {
+            prefix: prefix.into(),
+        };
+        assert_eq!(part2_with(TEST_INPUT_1, &params("row")), Ok(11));
+        assert_eq!(part2_with(TEST_INPUT_1, &params("s")), Ok(13));
+        assert_eq!(part2_with(TEST_INPUT_1, &params("")), Ok(11 * 12 * 13));
+    }
+
+    #[test]
+    fn invalid_input_is_reported() {
+        let input = "class: 1-3 or 5-7\nrow: 6-11 or 33-44\n\nyour ticket:\n7,1\n\nnearby tickets:\n7,3,47\n";
+        assert_eq!(part1(input), Err(Error::parse(8, 1, "Expected 2 values")));
+        assert_eq!(
+            part1("class: 1-3 or 5-7\n"),
+            Err(Error::parse(2, 1, "Expected your ticket"))
+        );
+    }
+
+    #[test]
+    fn more_rules_than_bits_in_a_word() {
+        // Every value only matches the rule of its own position
+        let rules = (1..=70).map(|value| {
+            let name = if value % 35 == 0 {
+                "departure"
+            } else {
+                "field"
+            };
+            format!("{name} {value}: {value}-{value} or 1000-1000")
+        });
+        let ticket = (1..=70).join(",");
+        let input = format!(
+            "{}\n\nyour ticket:\n{ticket}\n\nnearby tickets:\n{ticket}\n",
+            rules.collect::<Vec<_>>().join("\n")
+        );
+        assert_eq!(part2(&input), Ok(35 * 70));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn notes_round_trip_through_json() {
+        let text = Text::new(TEST_INPUT_0);
+        let notes = Solution::parse(&text).unwrap();
+        let json = serde_json::to_string(&notes).unwrap();
+        assert!(json.starts_with(
+            r#"{"rules":[{"name":"class","ranges":[{"start":1,"end":3},{"start":5,"end":7}]},"#
+        ));
+        assert_eq!(serde_json::from_str::<Notes>(&json).unwrap(), notes);
+        assert_eq!(
+            Solution::part1(&serde_json::from_str(&json).unwrap()),
+            Ok(71)
+        );
+
+        let json = r#"{"rules":[{"name":"class\u0020one","ranges":[{"start":1,"end":3},{"start":5,"end":7}]}],"my_ticket":[7],"nearby_tickets":[[3],[1,2]]}"#;
+        let notes: Notes = serde_json::from_str(json).unwrap();
+        assert_eq!(notes.rules[0].name(), "class one");
+        assert_eq!(Solution::part2(&notes), Some(Err(Error::NoSolution)));
+    }
+
+    fn reference_answers(input: &str) -> (usize, usize) {
+        let (rules, tickets) = input.split_once("\n\nyour ticket:\n").unwrap();
+        let (my_ticket, nearby_tickets) = tickets.split_once("\n\nnearby tickets:\n").unwrap();
+
+        let rules: Vec<(&str, Vec<usize>)> = rules
+            .lines()
+            .map(|line| {
+                let (name, ranges) = line.split_once(": ").unwrap();
+                let bounds = ranges
+                    .split(" or ")
+                    .flat_map(|range| range.split('-'))
+                    .map(|bound| bound.parse().unwrap())
+                    .collect();
+                (name, bounds)
+            })
+            .collect();
+        let matches = |bounds: &[usize], value: usize| {
+            bounds[0] <= value && value <= bounds[1] || bounds[2] <= value && value <= bounds[3]
+        };
+        let parse_ticket =
+            |line: &str| -> Vec<usize> { line.split(',').map(|s| s.parse().unwrap()).collect() };
+
+        let mut error_rate = 0;
+        let mut valid_tickets = Vec::new();
+        for ticket in nearby_tickets.lines().map(parse_ticket) {
+            let invalid: Vec<usize> = ticket
+                .iter()
+                .copied()
+                .filter(|value| !rules.iter().any(|(_, bounds)| matches(bounds, *value)))
+                .collect();
+            error_rate += invalid.iter().sum::<usize>();
+            if invalid.is_empty() {
+                valid_tickets.push(ticket);
+            }
+        }
+
+        let my_ticket = parse_ticket(my_ticket);
+        let products: Vec<usize> = (0..rules.len())
+            .permutations(rules.len())
+            .filter(|assignment| {
+                assignment.iter().enumerate().all(|(position, rule)| {
+                    valid_tickets
+                        .iter()
+                        .all(|ticket| matches(&rules[*rule].1, ticket[position]))
+                })
+            })
+            .map(|assignment| {
+                assignment
+                    .iter()
+                    .zip(&my_ticket)
+                    .filter(|(rule, _)| rules[**rule].0.starts_with("departure"))
+                    .map(|(_, value)| value)
+                    .product()
+            })
+            .collect();
+
+        assert_eq!(products.len(), 1);
+        (error_rate, products[0])
+    }
+
+    #[test]
+    fn random_inputs_match_reference() {
+        for seed in 0..100 {
+            let input = generate(&mut Rng::new(seed));
+            let (expected1, expected2) = reference_answers(&input);
+            assert_eq!(part1(&input), Ok(expected1), "seed {seed}");
+            assert_eq!(part2(&input), Ok(expected2), "seed {seed}");
+        }
+    }
+}
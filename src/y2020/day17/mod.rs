@@ -0,0 +1,326 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{
+    automaton::Sparse,
+    collections::HashSet,
+    grid::Grid,
+    observer::{notify, Ignore, Observer},
+    random::Rng,
+    solver::Solver,
+    text::Text,
+    Error, Result,
+};
+
+pub type Int = i8;
+
+const CYCLES: usize = 6;
+
+// Leaves room for the cubes to grow for the default number of cycles without overflowing `Int`.
+const MAX_SIZE: usize = Int::MAX as usize - CYCLES;
+
+/// Puzzle constants, defaulting to those of the actual puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    /// The number of cycles to simulate.
+    pub cycles: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params { cycles: CYCLES }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<(Int, Int)>> {
+    let mut cubes = Vec::new();
+
+    for (y, line) in input.lines().enumerate() {
+        for (x, (index, c)) in line.char_indices().enumerate() {
+            if x >= MAX_SIZE || y >= MAX_SIZE {
+                return Err(Error::at(input, &line[index..], "Grid is too large"));
+            }
+
+            match c {
+                '#' => cubes.push((x as Int, y as Int)),
+                '.' => {}
+                _ => return Err(Error::at(input, &line[index..], "Expected `.` or `#`")),
+            }
+        }
+    }
+
+    Ok(cubes)
+}
+
+/// Yields the `3^N - 1` cubes around `cube`.
+fn neighbors<const N: usize>(cube: [Int; N]) -> impl Iterator<Item = [Int; N]> {
+    (0..3usize.pow(N as u32))
+        .map(move |mut index| {
+            let mut neighbor = cube;
+            for coordinate in neighbor.iter_mut() {
+                *coordinate += (index % 3) as Int - 1;
+                index /= 3;
+            }
+            neighbor
+        })
+        .filter(move |neighbor| *neighbor != cube)
+}
+
+/// Returns `Error::NoSolution` if the cubes could grow past the range of `Int`.
+fn simulate<const N: usize>(
+    initial_cubes: &[(Int, Int)],
+    cycles: usize,
+    observer: &mut impl Observer<HashSet<[Int; N]>>,
+) -> Result<usize> {
+    let size = initial_cubes
+        .iter()
+        .map(|&(x, y)| x.max(y) as usize + 1)
+        .max()
+        .unwrap_or(0);
+    if size + cycles > Int::MAX as usize + 1 {
+        return Err(Error::NoSolution);
+    }
+
+    let mut automaton = Sparse::new(initial_cubes.iter().map(|&(x, y)| {
+        let mut cube = [0; N];
+        cube[0] = x;
+        cube[1] = y;
+        cube
+    }));
+
+    for _ in 0..cycles {
+        automaton.step(neighbors, |active, active_neighbor_count| {
+            active_neighbor_count == 3 || active && active_neighbor_count == 2
+        });
+        notify(observer, automaton.active())?;
+    }
+
+    Ok(automaton.active_count())
+}
+
+fn simulate_3d(
+    initial_cubes: &[(Int, Int)],
+    cycles: usize,
+    observer: &mut impl Observer<HashSet<[Int; 3]>>,
+) -> Result<usize> {
+    simulate(initial_cubes, cycles, observer)
+}
+
+fn simulate_4d(
+    initial_cubes: &[(Int, Int)],
+    cycles: usize,
+    observer: &mut impl Observer<HashSet<[Int; 4]>>,
+) -> Result<usize> {
+    simulate(initial_cubes, cycles, observer)
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    part1_with(input, &Params::default())
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    part2_with(input, &Params::default())
+}
+
+pub fn part1_with(input: &str, params: &Params) -> Result<usize> {
+    part1_observed(input, params, &mut Ignore)
+}
+
+pub fn part2_with(input: &str, params: &Params) -> Result<usize> {
+    part2_observed(input, params, &mut Ignore)
+}
+
+/// Like [`part1_with`], but passes the active cubes to `observer` after each cycle.
+pub fn part1_observed(
+    input: &str,
+    params: &Params,
+    observer: &mut impl Observer<HashSet<[Int; 3]>>,
+) -> Result<usize> {
+    simulate_3d(
+        &Solution::parse(&Text::new(input))?,
+        params.cycles,
+        observer,
+    )
+}
+
+/// Like [`part2_with`], but passes the active cubes to `observer` after each cycle.
+pub fn part2_observed(
+    input: &str,
+    params: &Params,
+    observer: &mut impl Observer<HashSet<[Int; 4]>>,
+) -> Result<usize> {
+    simulate_4d(
+        &Solution::parse(&Text::new(input))?,
+        params.cycles,
+        observer,
+    )
+}
+
+/// Generates a small initial slice of random size and density.
+pub fn generate(rng: &mut Rng) -> String {
+    let size = rng.range(1..9);
+    let density = rng.range(20..60) as f64 / 100.0;
+
+    Grid::from_fn(size, size, |_| if rng.chance(density) { '#' } else { '.' }).to_string()
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Vec<(Int, Int)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<'a>(input: &'a Text) -> Result<Self::Input<'a>> {
+        parse_input(input)
+    }
+
+    fn part1(initial_cubes: &Self::Input<'_>) -> Result<Self::Answer1> {
+        simulate_3d(initial_cubes, CYCLES, &mut Ignore)
+    }
+
+    fn part2(initial_cubes: &Self::Input<'_>) -> Option<Result<Self::Answer2>> {
+        Some(simulate_4d(initial_cubes, CYCLES, &mut Ignore))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::observer::Trace;
+    use crate::text::resaved;
+    use std::ops::ControlFlow;
+
+    static TEST_INPUT: &str = include_str!("test_input.txt");
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(part1(TEST_INPUT), Ok(112));
+    }
+
+    #[test]
+    fn part2_works() {
+        assert_eq!(part2(TEST_INPUT), Ok(848));
+    }
+
+    #[test]
+    fn resaved_input_works() {
+        for input in &resaved(TEST_INPUT) {
+            assert_eq!(part1(input), part1(TEST_INPUT));
+            assert_eq!(part2(input), part2(TEST_INPUT));
+        }
+    }
+
+    #[test]
+    fn params_change_the_cycles() {
+        let params = Params { cycles: 1 };
+        assert_eq!(part1_with(TEST_INPUT, &params), Ok(11));
+        assert_eq!(part2_with(TEST_INPUT, &params), Ok(29));
+
+        let params = Params { cycles: 200 };
+        assert_eq!(part1_with(TEST_INPUT, &params), Err(Error::NoSolution));
+    }
+
+    #[test]
+    fn observer_sees_each_cycle() {
+        let params = Params { cycles: 3 };
+        let mut trace = Trace::new();
+        assert_eq!(part1_observed(TEST_INPUT, &params, &mut trace), Ok(38));
+        assert_eq!(
+            trace.states.iter().map(HashSet::len).collect::<Vec<_>>(),
+            [11, 21, 38]
+        );
+        assert!(trace.states[0].contains(&[0, 1, -1]));
+
+        let mut stop_when_large = |cubes: &HashSet<[Int; 4]>| {
+            if cubes.len() > 100 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        };
+        assert_eq!(
+            part2_observed(TEST_INPUT, &Params::default(), &mut stop_when_large),
+            Err(Error::Stopped)
+        );
+    }
+
+    /// Simulates a dense array of cubes in three or four dimensions, visiting only the region
+    /// that the active cubes can have reached after each cycle.
+    fn reference_active(input: &str, dimensions: usize) -> usize {
+        let size = input.lines().count();
+        let w_extent = if dimensions == 4 { 15 } else { 1 };
+        let [ex, ey, ez] = [size + 14, size + 14, 15];
+        let index = |x: usize, y: usize, z: usize, w: usize| ((w * ez + z) * ey + y) * ex + x;
+
+        let mut active = vec![false; ex * ey * ez * w_extent];
+        for (y, line) in input.lines().enumerate() {
+            for (x, c) in line.bytes().enumerate() {
+                active[index(x + 7, y + 7, 7, w_extent / 2)] = c == b'#';
+            }
+        }
+
+        let dw_range = if dimensions == 4 { -1..=1 } else { 0..=0 };
+        let mut deltas = Vec::new();
+        for dw in dw_range {
+            for dz in -1..=1 {
+                for dy in -1..=1 {
+                    for dx in -1..=1 {
+                        if (dx, dy, dz, dw) != (0, 0, 0, 0) {
+                            deltas.push(
+                                ((dw * ez as isize + dz) * ey as isize + dy) * ex as isize + dx,
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        for cycle in 1..=6 {
+            let mut next = vec![false; active.len()];
+            let w_range = if dimensions == 4 {
+                7 - cycle..8 + cycle
+            } else {
+                0..1
+            };
+
+            for w in w_range {
+                for z in 7 - cycle..8 + cycle {
+                    for y in 7 - cycle..size + 7 + cycle {
+                        for x in 7 - cycle..size + 7 + cycle {
+                            let i = index(x, y, z, w);
+                            let count = deltas
+                                .iter()
+                                .filter(|delta| active[(i as isize + **delta) as usize])
+                                .count();
+                            next[i] = count == 3 || count == 2 && active[i];
+                        }
+                    }
+                }
+            }
+
+            active = next;
+        }
+
+        active.iter().filter(|cube| **cube).count()
+    }
+
+    #[test]
+    fn random_inputs_match_reference() {
+        for seed in 0..5 {
+            let input = generate(&mut Rng::new(seed));
+            assert_eq!(
+                part1(&input),
+                Ok(reference_active(&input, 3)),
+                "seed {seed}"
+            );
+            assert_eq!(
+                part2(&input),
+                Ok(reference_active(&input, 4)),
+                "seed {seed}"
+            );
+        }
+    }
+}
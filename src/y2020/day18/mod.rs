@@ -0,0 +1,435 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::io::BufRead;
+
+#[cfg(feature = "std")]
+use crate::stream::Lines;
+use crate::{error::parse_at, random::Rng, solver::Solver, text::Text, Error, Result};
+
+type Number = u64;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Operator {
+    Add,
+    Multiply,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Token {
+    Number(Number),
+    Operator(Operator),
+    LeftParenthesis,
+    RightParenthesis,
+}
+
+/// Tokenizes a line of `input`, checking that it forms a well-formed expression.
+fn tokens(input: &str, line: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut it = line.char_indices().peekable();
+    let mut expects_operand = true;
+    let mut depth = 0usize;
+
+    while let Some((start, c)) = it.next() {
+        let rest = &line[start..];
+
+        let token = match c {
+            ' ' => continue,
+            '0'..='9' if expects_operand => {
+                let mut end = line.len();
+                while let Some((i, c)) = it.peek() {
+                    if c.is_ascii_digit() {
+                        it.next();
+                    } else {
+                        end = *i;
+                        break;
+                    }
+                }
+                Token::Number(parse_at(input, &line[start..end])?)
+            }
+            '(' if expects_operand => {
+                depth += 1;
+                Token::LeftParenthesis
+            }
+            '+' if !expects_operand => Token::Operator(Operator::Add),
+            '*' if !expects_operand => Token::Operator(Operator::Multiply),
+            ')' if !expects_operand && depth > 0 => {
+                depth -= 1;
+                Token::RightParenthesis
+            }
+            _ if expects_operand => return Err(Error::at(input, rest, "Expected a number or `(`")),
+            _ => return Err(Error::at(input, rest, "Expected `+`, `*` or `)`")),
+        };
+
+        expects_operand = matches!(token, Token::Operator(_) | Token::LeftParenthesis);
+        tokens.push(token);
+    }
+
+    if expects_operand {
+        Err(Error::at(
+            input,
+            &line[line.len()..],
+            "Expected a number or `(`",
+        ))
+    } else if depth > 0 {
+        Err(Error::at(input, &line[line.len()..], "Expected `)`"))
+    } else {
+        Ok(tokens)
+    }
+}
+
+fn rpn_tokens<'a>(
+    tokens: impl IntoIterator<Item = Token> + 'a,
+    operator_precedence: impl Fn(Operator) -> usize + 'a,
+) -> impl Iterator<Item = Token> + 'a {
+    let mut operators = Vec::new();
+    let mut token_it = tokens.into_iter();
+
+    enum State {
+        Initial,
+        Operator(Operator),
+        RightParenthesis,
+        Final,
+    }
+
+    let mut state = State::Initial;
+
+    core::iter::from_fn(move || loop {
+        match state {
+            State::Initial => match token_it.next() {
+                Some(token @ Token::Number(_)) => {
+                    break Some(token);
+                }
+                Some(Token::Operator(operator)) => {
+                    state = State::Operator(operator);
+                }
+                Some(token @ Token::LeftParenthesis) => {
+                    operators.push(token);
+                }
+                Some(Token::RightParenthesis) => {
+                    state = State::RightParenthesis;
+                }
+                None => {
+                    state = State::Final;
+                }
+            },
+            State::Operator(operator) => {
+                if operators
+                    .last()
+                    .filter(|other_token| match other_token {
+                        Token::Operator(other_operator) => {
+                            operator_precedence(*other_operator) >= operator_precedence(operator)
+                        }
+                        _ => false,
+                    })
+                    .is_some()
+                {
+                    break Some(operators.pop().unwrap());
+                } else {
+                    operators.push(Token::Operator(operator));
+                    state = State::Initial;
+                }
+            }
+            State::RightParenthesis => {
+                if operators
+                    .last()
+                    .filter(|token| !matches!(token, Token::LeftParenthesis))
+                    .is_some()
+                {
+                    break Some(operators.pop().unwrap());
+                } else {
+                    if let Some(Token::LeftParenthesis) = operators.last() {
+                        operators.pop().unwrap();
+                    }
+                    state = State::Initial;
+                }
+            }
+            State::Final => {
+                break operators.pop();
+            }
+        }
+    })
+}
+
+/// Evaluates well-formed `tokens`, with no answer if the value does not fit in a `Number`.
+fn evaluate(tokens: &[Token], operator_precedence: impl Fn(Operator) -> usize) -> Result<Number> {
+    let mut stack = Vec::new();
+
+    for rpn_token in rpn_tokens(tokens.iter().copied(), operator_precedence) {
+        match rpn_token {
+            Token::Number(value) => {
+                stack.push(value);
+            }
+            Token::Operator(operator) => {
+                let lhs = stack.pop().unwrap();
+                let rhs = stack.pop().unwrap();
+                let value = match operator {
+                    Operator::Add => lhs.checked_add(rhs),
+                    Operator::Multiply => lhs.checked_mul(rhs),
+                };
+                stack.push(value.ok_or(Error::NoSolution)?);
+            }
+            _ => panic!("Unexpected token: {:?}", rpn_token),
+        }
+    }
+
+    Ok(stack.pop().unwrap())
+}
+
+fn equal_precedence(_: Operator) -> usize {
+    1
+}
+
+fn addition_first(operator: Operator) -> usize {
+    match operator {
+        Operator::Add => 2,
+        Operator::Multiply => 1,
+    }
+}
+
+fn checked_sum(values: impl IntoIterator<Item = Result<Number>>) -> Result<Number> {
+    values.into_iter().try_fold(0, |sum: Number, value| {
+        sum.checked_add(value?).ok_or(Error::NoSolution)
+    })
+}
+
+fn sum_with_equal_precedence(expressions: &[Vec<Token>]) -> Result<Number> {
+    checked_sum(
+        expressions
+            .iter()
+            .map(|tokens| evaluate(tokens, equal_precedence)),
+    )
+}
+
+fn sum_with_addition_first(expressions: &[Vec<Token>]) -> Result<Number> {
+    checked_sum(
+        expressions
+            .iter()
+            .map(|tokens| evaluate(tokens, addition_first)),
+    )
+}
+
+/// Sums the expressions while reading the input line by line.
+#[cfg(feature = "std")]
+fn sum_in(reader: impl BufRead, operator_precedence: fn(Operator) -> usize) -> Result<Number> {
+    let mut lines = Lines::new(reader);
+    let mut sum: Number = 0;
+
+    while let Some(expression) = lines.parse_next(|line| tokens(line, line))? {
+        let value = evaluate(&expression, operator_precedence)?;
+        sum = sum.checked_add(value).ok_or(Error::NoSolution)?;
+    }
+
+    Ok(sum)
+}
+
+pub fn part1(input: &str) -> Result<Number> {
+    sum_with_equal_precedence(&Solution::parse(&Text::new(input))?)
+}
+
+pub fn part2(input: &str) -> Result<Number> {
+    sum_with_addition_first(&Solution::parse(&Text::new(input))?)
+}
+
+/// Like [`part1`], but reads the input line by line.
+#[cfg(feature = "std")]
+pub fn part1_from_reader(reader: impl BufRead) -> Result<Number> {
+    sum_in(reader, equal_precedence)
+}
+
+/// Like [`part2`], but reads the input line by line.
+#[cfg(feature = "std")]
+pub fn part2_from_reader(reader: impl BufRead) -> Result<Number> {
+    sum_in(reader, addition_first)
+}
+
+fn random_expression(rng: &mut Rng, depth: usize) -> String {
+    let mut expression = String::new();
+
+    for index in 0..rng.range(2..5) {
+        if index > 0 {
+            expression.push_str(if rng.chance(0.5) { " + " } else { " * " });
+        }
+        if depth > 0 && rng.chance(0.3) {
+            expression.push('(');
+            expression.push_str(&random_expression(rng, depth - 1));
+            expression.push(')');
+        } else {
+            expression.push_str(&rng.range(1..10).to_string());
+        }
+    }
+
+    expression
+}
+
+/// Generates expressions with single-digit numbers and up to two levels of nested parentheses.
+pub fn generate(rng: &mut Rng) -> String {
+    (0..rng.range(1..50))
+        .map(|_| random_expression(rng, 2))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Vec<Vec<Token>>;
+    type Answer1 = Number;
+    type Answer2 = Number;
+
+    fn parse<'a>(input: &'a Text) -> Result<Self::Input<'a>> {
+        input.lines().map(|line| tokens(input, line)).collect()
+    }
+
+    fn part1(expressions: &Self::Input<'_>) -> Result<Self::Answer1> {
+        sum_with_equal_precedence(expressions)
+    }
+
+    fn part2(expressions: &Self::Input<'_>) -> Option<Result<Self::Answer2>> {
+        Some(sum_with_addition_first(expressions))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::resaved;
+
+    static INPUT: &str = include_str!("input.txt");
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(part1("1 + 2 * 3 + 4 * 5 + 6"), Ok(71));
+        assert_eq!(part1("1 + (2 * 3) + (4 * (5 + 6))"), Ok(51));
+        assert_eq!(part1("2 * 3 + (4 * 5)"), Ok(26));
+        assert_eq!(part1("5 + (8 * 3 + 9 + 3 * 4 * 3)"), Ok(437));
+        assert_eq!(
+            part1("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))"),
+            Ok(12_240)
+        );
+        assert_eq!(
+            part1("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2"),
+            Ok(13_632)
+        );
+    }
+
+    #[test]
+    fn part2_works() {
+        assert_eq!(part2("1 + 2 * 3 + 4 * 5 + 6"), Ok(231));
+        assert_eq!(part2("1 + (2 * 3) + (4 * (5 + 6))"), Ok(51));
+        assert_eq!(part2("2 * 3 + (4 * 5)"), Ok(46));
+        assert_eq!(part2("5 + (8 * 3 + 9 + 3 * 4 * 3)"), Ok(1445));
+        assert_eq!(
+            part2("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))"),
+            Ok(669_060)
+        );
+        assert_eq!(
+            part2("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2"),
+            Ok(23_340)
+        );
+    }
+
+    #[test]
+    fn resaved_input_works() {
+        for input in &resaved(INPUT) {
+            assert_eq!(part1(input), part1(INPUT));
+            assert_eq!(part2(input), part2(INPUT));
+        }
+    }
+
+    #[test]
+    fn invalid_input_is_reported() {
+        assert_eq!(
+            part1("1 + 2\n3 + / 4"),
+            Err(Error::parse(2, 5, "Expected a number or `(`"))
+        );
+        assert_eq!(part1("(1 + 2"), Err(Error::parse(1, 7, "Expected `)`")));
+        assert_eq!(
+            part1("1 + 2)"),
+            Err(Error::parse(1, 6, "Expected `+`, `*` or `)`"))
+        );
+    }
+
+    /// Evaluates by recursive descent, where `level` 0 is multiplication, 1 is addition and 2 is
+    /// a number or parenthesized expression. With equal precedence, both operators share level 0.
+    #[cfg(feature = "std")]
+    #[test]
+    fn reader_gives_same_results() {
+        for input in &resaved(INPUT) {
+            assert_eq!(part1_from_reader(input.as_bytes()), part1(input));
+            assert_eq!(part2_from_reader(input.as_bytes()), part2(input));
+        }
+        for input in [INPUT, "1 + 2\n3 + / 4", "1 + 2\n\n"] {
+            assert_eq!(part1_from_reader(input.as_bytes()), part1(input));
+            assert_eq!(part2_from_reader(input.as_bytes()), part2(input));
+        }
+        for seed in 0..20 {
+            let input = generate(&mut Rng::new(seed));
+            assert_eq!(part1_from_reader(input.as_bytes()), part1(&input));
+            assert_eq!(part2_from_reader(input.as_bytes()), part2(&input));
+        }
+    }
+
+    fn reference_evaluate(
+        tokens: &mut std::iter::Peekable<std::str::Chars>,
+        level: usize,
+        equal: bool,
+    ) -> Number {
+        let operand_level = if equal { 2 } else { level + 1 };
+
+        if level == 2 {
+            return match tokens.next().unwrap() {
+                '(' => {
+                    let value = reference_evaluate(tokens, 0, equal);
+                    assert_eq!(tokens.next(), Some(')'));
+                    value
+                }
+                digit => digit.to_digit(10).unwrap() as Number,
+            };
+        }
+
+        let mut value = reference_evaluate(tokens, operand_level, equal);
+        loop {
+            match tokens.peek() {
+                Some('*') if level == 0 => {
+                    tokens.next();
+                    value *= reference_evaluate(tokens, operand_level, equal);
+                }
+                Some('+') if level == 1 || equal => {
+                    tokens.next();
+                    value += reference_evaluate(tokens, operand_level, equal);
+                }
+                _ => break value,
+            }
+        }
+    }
+
+    fn reference_sum(input: &str, equal: bool) -> Number {
+        input
+            .lines()
+            .map(|line| {
+                let expression = line.replace(' ', "");
+                reference_evaluate(&mut expression.chars().peekable(), 0, equal)
+            })
+            .sum()
+    }
+
+    #[test]
+    fn random_inputs_match_reference() {
+        for seed in 0..100 {
+            let input = generate(&mut Rng::new(seed));
+            assert_eq!(
+                part1(&input),
+                Ok(reference_sum(&input, true)),
+                "seed {seed}"
+            );
+            assert_eq!(
+                part2(&input),
+                Ok(reference_sum(&input, false)),
+                "seed {seed}"
+            );
+        }
+    }
+}
@@ -0,0 +1,423 @@
+use alloc::{collections::BTreeMap, format, string::String, vec, vec::Vec};
+
+use itertools::Itertools;
+
+use crate::{
+    error::parse_at, parser::Parser, random::Rng, reduce::Structure, solver::Solver, text::Text,
+    Error, Result,
+};
+
+type RuleRef = usize;
+
+#[derive(Debug, Clone)]
+enum Rule<'a> {
+    Text(&'a str),
+    Refs(&'a str),
+}
+
+fn parse_rule<'a>(p: &mut Parser<'a>) -> Result<(RuleRef, Rule<'a>)> {
+    let index = p.number()?;
+    p.tag(": ")?;
+
+    let rule = if p.eat("\"") {
+        let text = p.take_until("\"")?;
+        p.tag("\"")?;
+        Rule::Text(text)
+    } else {
+        let refs = p.rest();
+        p.separated(" | ", |p| p.separated(" ", Parser::number::<RuleRef>))?;
+        Rule::Refs(&refs[..refs.len() - p.rest().len()])
+    };
+
+    Ok((index, rule))
+}
+
+fn rule_refs(refs: &str) -> impl Iterator<Item = &str> {
+    refs.split(" | ").flat_map(|rule_refs| rule_refs.split(' '))
+}
+
+type RuleMap<'a> = BTreeMap<RuleRef, Rule<'a>>;
+
+pub struct Data<'a> {
+    rules: RuleMap<'a>,
+    messages: Vec<&'a str>,
+}
+
+fn parse_input(input: &str) -> Result<Data> {
+    let mut blocks = Parser::new(input).blocks();
+
+    let rules: RuleMap = match blocks.next() {
+        Some(block) => block.lines(parse_rule)?.into_iter().collect(),
+        None => RuleMap::new(),
+    };
+    let messages = blocks.flat_map(|block| block.rest().lines()).collect();
+
+    for rule in rules.values() {
+        if let Rule::Refs(refs) = rule {
+            for rule_ref in rule_refs(refs) {
+                if !rules.contains_key(&parse_at(input, rule_ref)?) {
+                    return Err(Error::at(
+                        input,
+                        rule_ref,
+                        format!("Undefined rule {rule_ref}"),
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(Data { rules, messages })
+}
+
+/// A rule whose references are resolved to indices into the list of rules.
+enum Resolved<'a> {
+    Text(&'a str),
+    Alternatives(Vec<Vec<usize>>),
+}
+
+/// What is known about the matches of a rule at a position of a message.
+#[derive(Clone)]
+enum Ends {
+    Unknown,
+    Pending,
+    Known(Vec<usize>),
+}
+
+struct Matcher<'a> {
+    rules: Vec<Resolved<'a>>,
+    root: usize,
+}
+
+impl<'a> Matcher<'a> {
+    /// Fails if rule 0 or a rule it refers to is missing.
+    fn new(rules: &RuleMap<'a>) -> Result<Self> {
+        let indices: BTreeMap<RuleRef, usize> = rules
+            .keys()
+            .enumerate()
+            .map(|(index, rule_ref)| (*rule_ref, index))
+            .collect();
+        let index = |rule_ref: &str| {
+            let rule_ref = rule_ref.parse::<RuleRef>().map_err(|_| Error::NoSolution)?;
+            indices.get(&rule_ref).copied().ok_or(Error::NoSolution)
+        };
+
+        let resolved = rules
+            .values()
+            .map(|rule| match rule {
+                Rule::Text(text) => Ok(Resolved::Text(text)),
+                Rule::Refs(refs) => refs
+                    .split(" | ")
+                    .map(|sequence| sequence.split(' ').map(index).collect())
+                    .collect::<Result<_>>()
+                    .map(Resolved::Alternatives),
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Matcher {
+            rules: resolved,
+            root: index("0")?,
+        })
+    }
+
+    fn is_match(&self, message: &str) -> Result<bool> {
+        let positions = message.len() + 1;
+        let mut ends = vec![Ends::Unknown; self.rules.len() * positions];
+        let root_ends = self.ends(message.as_bytes(), self.root, 0, &mut ends)?;
+        Ok(root_ends.contains(&message.len()))
+    }
+
+    /// Returns every position where a match of `rule` that starts at `start` could end.
+    ///
+    /// A rule that refers back to itself without consuming any text has no finite match, which is
+    /// reported as having no solution.
+    fn ends(
+        &self,
+        message: &[u8],
+        rule: usize,
+        start: usize,
+        memo: &mut [Ends],
+    ) -> Result<Vec<usize>> {
+        let key = rule * (message.len() + 1) + start;
+        match &memo[key] {
+            Ends::Known(ends) => return Ok(ends.clone()),
+            Ends::Pending => return Err(Error::NoSolution),
+            Ends::Unknown => memo[key] = Ends::Pending,
+        }
+
+        let ends = match &self.rules[rule] {
+            Resolved::Text(text) if message[start..].starts_with(text.as_bytes()) => {
+                vec![start + text.len()]
+            }
+            Resolved::Text(_) => Vec::new(),
+            Resolved::Alternatives(alternatives) => {
+                let mut ends = Vec::new();
+                for sequence in alternatives {
+                    let mut sequence_ends = vec![start];
+                    for rule in sequence {
+                        let mut next = Vec::new();
+                        for end in sequence_ends {
+                            next.extend(self.ends(message, *rule, end, memo)?);
+                        }
+                        next.sort_unstable();
+                        next.dedup();
+                        sequence_ends = next;
+                    }
+                    ends.extend(sequence_ends);
+                }
+                ends.sort_unstable();
+                ends.dedup();
+                ends
+            }
+        };
+
+        memo[key] = Ends::Known(ends.clone());
+        Ok(ends)
+    }
+}
+
+fn count_matching(rules: &RuleMap, messages: &[&str]) -> Result<usize> {
+    let matcher = Matcher::new(rules)?;
+
+    messages.iter().try_fold(0, |count, message| {
+        Ok(if matcher.is_match(message)? {
+            count + 1
+        } else {
+            count
+        })
+    })
+}
+
+fn count_valid_messages(data: &Data) -> Result<usize> {
+    count_matching(&data.rules, &data.messages)
+}
+
+fn count_valid_messages_with_loops(data: &Data) -> Result<usize> {
+    let mut rules = data.rules.clone();
+
+    rules.insert(8, Rule::Refs("42 | 42 8"));
+    rules.insert(11, Rule::Refs("42 31 | 42 11 31"));
+
+    count_matching(&rules, &data.messages)
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    count_valid_messages(&Solution::parse(&Text::new(input))?)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    count_valid_messages_with_loops(&Solution::parse(&Text::new(input))?)
+}
+
+/// A generated rule, as alternatives of sequences of indices into the generated rules.
+enum RandomRule {
+    Text(char),
+    Alternatives(Vec<Vec<usize>>),
+}
+
+fn derive_message(rules: &[RandomRule], index: usize, rng: &mut Rng, message: &mut String) {
+    match &rules[index] {
+        RandomRule::Text(c) => message.push(*c),
+        RandomRule::Alternatives(alternatives) => {
+            for index in rng.choose(alternatives) {
+                derive_message(rules, *index, rng, message);
+            }
+        }
+    }
+}
+
+/// Generates acyclic rules shaped like the puzzle's, where rule 0 is `8 11`, rule 8 is `42` and
+/// rule 11 is `42 31`, with messages that match with and without the looping rules.
+pub fn generate(rng: &mut Rng) -> String {
+    let mut rules = vec![RandomRule::Text('a'), RandomRule::Text('b')];
+
+    // Rules only refer to earlier rules, and the last two become rules 42 and 31
+    for _ in 0..rng.range(2..7) {
+        let alternatives = (0..rng.range(1..3))
+            .map(|_| {
+                (0..rng.range(1..3))
+                    .map(|_| rng.range(0..rules.len()))
+                    .collect()
+            })
+            .collect();
+        rules.push(RandomRule::Alternatives(alternatives));
+    }
+    let (rule_42, rule_31) = (rules.len() - 2, rules.len() - 1);
+
+    let mut rule_refs: Vec<RuleRef> = (1..100)
+        .filter(|rule_ref| ![8, 11, 31, 42].contains(rule_ref))
+        .collect();
+    rng.shuffle(&mut rule_refs);
+    rule_refs.truncate(rules.len() - 2);
+    rule_refs.extend([42, 31]);
+
+    let mut lines: Vec<String> = rules
+        .iter()
+        .zip(&rule_refs)
+        .map(|(rule, rule_ref)| match rule {
+            RandomRule::Text(c) => format!("{rule_ref}: \"{c}\""),
+            RandomRule::Alternatives(alternatives) => {
+                let alternatives = alternatives
+                    .iter()
+                    .map(|refs| refs.iter().map(|index| rule_refs[*index]).join(" "))
+                    .join(" | ");
+                format!("{rule_ref}: {alternatives}")
+            }
+        })
+        .chain(["0: 8 11".into(), "8: 42".into(), "11: 42 31".into()])
+        .collect();
+    rng.shuffle(&mut lines);
+
+    let messages = (0..rng.range(5..40)).map(|_| {
+        let mut message = String::new();
+        let nested = rng.range(1..4);
+        for _ in 0..rng.range(1..4) + nested {
+            derive_message(&rules, rule_42, rng, &mut message);
+        }
+        for _ in 0..nested {
+            derive_message(&rules, rule_31, rng, &mut message);
+        }
+
+        if rng.chance(0.3) {
+            let index = rng.range(0..message.len());
+            let flipped = if &message[index..=index] == "a" {
+                "b"
+            } else {
+                "a"
+            };
+            message.replace_range(index..=index, flipped);
+        }
+        message
+    });
+
+    format!(
+        "{}\n\n{}",
+        lines.join("\n"),
+        messages.collect::<Vec<_>>().join("\n")
+    )
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Data<'a>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const STRUCTURE: Structure = Structure::Sections;
+
+    fn parse<'a>(input: &'a Text) -> Result<Self::Input<'a>> {
+        parse_input(input)
+    }
+
+    fn part1(data: &Self::Input<'_>) -> Result<Self::Answer1> {
+        count_valid_messages(data)
+    }
+
+    fn part2(data: &Self::Input<'_>) -> Option<Result<Self::Answer2>> {
+        Some(count_valid_messages_with_loops(data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::resaved;
+    use std::collections::HashMap;
+
+    static TEST_INPUT_0: &str = include_str!("test_input_0.txt");
+    static TEST_INPUT_1: &str = include_str!("test_input_1.txt");
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(part1(TEST_INPUT_0), Ok(2));
+        assert_eq!(part1(TEST_INPUT_1), Ok(3));
+    }
+
+    #[test]
+    fn part2_works() {
+        assert_eq!(part2(TEST_INPUT_1), Ok(12));
+    }
+
+    #[test]
+    fn resaved_input_works() {
+        for input in &resaved(TEST_INPUT_1) {
+            assert_eq!(part1(input), part1(TEST_INPUT_1));
+            assert_eq!(part2(input), part2(TEST_INPUT_1));
+        }
+    }
+
+    #[test]
+    fn invalid_input_is_reported() {
+        assert_eq!(
+            part1("0: 1 2\n1: \"a\"\n\na\n"),
+            Err(Error::parse(1, 6, "Undefined rule 2"))
+        );
+    }
+
+    /// Returns every position where a match of `rule` that starts at `start` could end.
+    fn reference_ends(
+        rules: &HashMap<&str, &str>,
+        rule: &str,
+        message: &str,
+        start: usize,
+    ) -> Vec<usize> {
+        let body = rules[rule];
+
+        if let Some(text) = body.strip_prefix('"') {
+            let text = text.trim_end_matches('"');
+            return if message[start..].starts_with(text) {
+                vec![start + text.len()]
+            } else {
+                vec![]
+            };
+        }
+
+        let mut ends: Vec<usize> = body
+            .split(" | ")
+            .flat_map(|sequence| {
+                sequence.split(' ').fold(vec![start], |ends, rule| {
+                    ends.into_iter()
+                        .flat_map(|end| reference_ends(rules, rule, message, end))
+                        .collect()
+                })
+            })
+            .collect();
+        ends.sort_unstable();
+        ends.dedup();
+        ends
+    }
+
+    fn reference_count(input: &str, loops: bool) -> usize {
+        let (rules, messages) = input.split_once("\n\n").unwrap();
+        let mut rules: HashMap<&str, &str> = rules
+            .lines()
+            .map(|line| line.split_once(": ").unwrap())
+            .collect();
+        if loops {
+            rules.insert("8", "42 | 42 8");
+            rules.insert("11", "42 31 | 42 11 31");
+        }
+
+        messages
+            .lines()
+            .filter(|message| reference_ends(&rules, "0", message, 0).contains(&message.len()))
+            .count()
+    }
+
+    #[test]
+    fn random_inputs_match_reference() {
+        for seed in 0..100 {
+            let input = generate(&mut Rng::new(seed));
+            assert_eq!(
+                part1(&input),
+                Ok(reference_count(&input, false)),
+                "seed {seed}"
+            );
+            assert_eq!(
+                part2(&input),
+                Ok(reference_count(&input, true)),
+                "seed {seed}"
+            );
+        }
+    }
+}
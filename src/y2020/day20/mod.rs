@@ -0,0 +1,558 @@
+use alloc::{format, string::String, vec::Vec};
+
+use crate::{
+    collections::{HashMap, HashSet},
+    error::parse_at,
+    grid::{Grid, GridView, Transform, TRANSFORMS},
+    random::Rng,
+    reduce::Structure,
+    solver::Solver,
+    text::Text,
+    Error, Result,
+};
+
+const TILE_SIZE: usize = 10;
+
+type TileId = u16;
+type Tile = Grid<bool>;
+type TileView<'a> = GridView<'a, bool>;
+
+type Position = [i16; 2];
+
+type RemainingTiles<'a> = HashMap<TileId, &'a Tile>;
+type TileArrangement<'a> = HashMap<Position, (TileId, TileView<'a>)>;
+
+fn parse_tile(input: &str, chunk: &str) -> Result<(TileId, Tile)> {
+    let (first_line, body) = chunk
+        .split_once('\n')
+        .unwrap_or((chunk, &chunk[chunk.len()..]));
+    let id = first_line
+        .strip_prefix("Tile ")
+        .and_then(|line| line.strip_suffix(':'))
+        .ok_or_else(|| Error::at(input, first_line, "Expected `Tile <id>:`"))?;
+    let id = parse_at(input, id)?;
+
+    let tile = Grid::parse_at(input, body, "`.` or `#`", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+
+    if tile.width() != TILE_SIZE || tile.height() != TILE_SIZE {
+        return Err(Error::at(
+            input,
+            body,
+            format!("Expected {TILE_SIZE} rows of {TILE_SIZE} pixels"),
+        ));
+    }
+
+    Ok((id, tile))
+}
+
+fn parse_tiles(input: &str) -> Result<Vec<(TileId, Tile)>> {
+    input
+        .split("\n\n")
+        .filter(|chunk| !chunk.trim().is_empty())
+        .map(|chunk| parse_tile(input, chunk))
+        .collect()
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Direction {
+    Top,
+    Left,
+    Bottom,
+    Right,
+}
+
+const DIRECTIONS: [Direction; 4] = [
+    Direction::Top,
+    Direction::Bottom,
+    Direction::Left,
+    Direction::Right,
+];
+
+impl Direction {
+    const fn opposite(&self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Left => Self::Right,
+            Self::Bottom => Self::Top,
+            Self::Right => Self::Left,
+        }
+    }
+}
+
+fn edge(tile_view: &TileView, dir: Direction) -> [bool; TILE_SIZE] {
+    let mut edge = [false; TILE_SIZE];
+
+    for (i, pixel) in edge.iter_mut().enumerate() {
+        *pixel = tile_view[match dir {
+            Direction::Top => (i, 0),
+            Direction::Left => (0, i),
+            Direction::Bottom => (i, TILE_SIZE - 1),
+            Direction::Right => (TILE_SIZE - 1, i),
+        }];
+    }
+
+    edge
+}
+
+fn tile_views(tile: &Tile) -> impl Iterator<Item = TileView<'_>> {
+    TRANSFORMS
+        .iter()
+        .map(move |transform| tile.view(*transform))
+}
+
+fn neighbors(pos: Position) -> impl Iterator<Item = (Direction, Position)> {
+    let [x, y] = pos;
+    DIRECTIONS.iter().copied().map(move |dir| {
+        (
+            dir,
+            match dir {
+                Direction::Top => [x, y - 1],
+                Direction::Bottom => [x, y + 1],
+                Direction::Left => [x - 1, y],
+                Direction::Right => [x + 1, y],
+            },
+        )
+    })
+}
+
+fn bounding_box<'a>(positions: impl IntoIterator<Item = &'a Position>) -> Option<[Position; 2]> {
+    positions.into_iter().fold(
+        Option::<[Position; 2]>::None,
+        |result, &[x, y]: &'a Position| {
+            if let Some([[min_x, min_y], [max_x, max_y]]) = result {
+                Some([[min_x.min(x), min_y.min(y)], [max_x.max(x), max_y.max(y)]])
+            } else {
+                Some([[x, y], [x, y]])
+            }
+        },
+    )
+}
+
+fn find_tile_arrangement_helper<'a>(
+    remaining_tiles: RemainingTiles<'a>,
+    tile_arrangement: TileArrangement<'a>,
+) -> Option<TileArrangement<'a>> {
+    if remaining_tiles.is_empty() {
+        Some(tile_arrangement)
+    } else {
+        remaining_tiles.iter().find_map(|(&tile_id, &tile)| {
+            tile_arrangement
+                .keys()
+                .copied()
+                .flat_map(neighbors)
+                .filter_map(|(_, pos)| {
+                    if tile_arrangement.contains_key(&pos) {
+                        None
+                    } else {
+                        Some(pos)
+                    }
+                })
+                .find_map(|pos| {
+                    tile_views(tile).find_map(|tile_view| {
+                        let valid = neighbors(pos)
+                            .filter_map(|(dir, neighbor_pos)| {
+                                Some((dir, tile_arrangement.get(&neighbor_pos)?))
+                            })
+                            .all(|(dir, (_, neighbor_tile_view))| {
+                                edge(neighbor_tile_view, dir.opposite()) == edge(&tile_view, dir)
+                            });
+
+                        if valid {
+                            let mut next_remaining_tiles = remaining_tiles.clone();
+                            next_remaining_tiles.remove(&tile_id);
+
+                            let mut next_tile_arrangement = tile_arrangement.clone();
+                            next_tile_arrangement.insert(pos, (tile_id, tile_view));
+
+                            find_tile_arrangement_helper(
+                                next_remaining_tiles,
+                                next_tile_arrangement,
+                            )
+                        } else {
+                            None
+                        }
+                    })
+                })
+        })
+    }
+}
+
+fn find_tile_arrangement<'a>(
+    tiles: impl IntoIterator<Item = &'a (TileId, Tile)>,
+) -> Option<TileArrangement<'a>> {
+    let mut tile_arrangement = TileArrangement::default();
+    let mut remaining_tiles: RemainingTiles = tiles
+        .into_iter()
+        .map(|(tile_id, tile)| (*tile_id, tile))
+        .collect();
+
+    let (&tile_id, _) = remaining_tiles.iter().next()?;
+    let tile = remaining_tiles.remove(&tile_id)?;
+
+    tile_arrangement.insert([0, 0], (tile_id, tile.view(Transform::IDENTITY)));
+
+    find_tile_arrangement_helper(remaining_tiles, tile_arrangement)
+}
+
+fn corner_tile_ids_product(tiles: &[(TileId, Tile)]) -> Result<u64> {
+    let tile_arrangement = find_tile_arrangement(tiles).ok_or(Error::NoSolution)?;
+
+    let [[min_x, min_y], [max_x, max_y]] = bounding_box(tile_arrangement.keys()).unwrap();
+
+    let corners = [
+        [min_x, min_y],
+        [min_x, max_y],
+        [max_x, min_y],
+        [max_x, max_y],
+    ];
+
+    corners
+        .iter()
+        .map(|corner| {
+            let (tile_id, _) = tile_arrangement.get(corner).ok_or(Error::NoSolution)?;
+            Ok(*tile_id as u64)
+        })
+        .product()
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Pixel {
+    Empty,
+    Filled,
+    SeaMonster,
+}
+
+const SEA_MONSTER_WIDTH: usize = 20;
+const SEA_MONSTER_HEIGHT: usize = 3;
+
+const SEA_MONSTER: [&[u8; SEA_MONSTER_WIDTH]; SEA_MONSTER_HEIGHT] = [
+    b"                  # ",
+    b"#    ##    ##    ###",
+    b" #  #  #  #  #  #   ",
+];
+
+/// Arranges the tiles into a square and joins them into an image, without their borders.
+fn assemble_image(tiles: &[(TileId, Tile)]) -> Result<Grid<bool>> {
+    let tile_arrangement = find_tile_arrangement(tiles).ok_or(Error::NoSolution)?;
+
+    let [[min_x, min_y], [max_x, max_y]] = bounding_box(tile_arrangement.keys()).unwrap();
+
+    let tiles_size = {
+        let ncols = 1 + max_x - min_x;
+        let nrows = 1 + max_y - min_y;
+        if ncols != nrows {
+            return Err(Error::NoSolution);
+        }
+        ncols as usize
+    };
+
+    let image_size = (TILE_SIZE - 2) * tiles_size;
+
+    if tile_arrangement.len() != tiles_size * tiles_size {
+        return Err(Error::NoSolution);
+    }
+
+    Ok(Grid::from_fn(image_size, image_size, |(x, y)| {
+        let (_, tile_view) = &tile_arrangement[&[
+            min_x + (x / (TILE_SIZE - 2)) as i16,
+            min_y + (y / (TILE_SIZE - 2)) as i16,
+        ]];
+
+        tile_view[(1 + x % (TILE_SIZE - 2), 1 + y % (TILE_SIZE - 2))]
+    }))
+}
+
+fn water_roughness(tiles: &[(TileId, Tile)]) -> Result<usize> {
+    let image = assemble_image(tiles)?;
+    let map_size = image.width();
+
+    let mut map = image.map(|_, filled| if *filled { Pixel::Filled } else { Pixel::Empty });
+
+    for transform in TRANSFORMS {
+        let source = |x, y| transform.source_position((x, y), map_size, map_size);
+
+        for y0 in 0..(map_size + 1).saturating_sub(SEA_MONSTER_HEIGHT) {
+            'check_sea_monster: for x0 in 0..(map_size + 1).saturating_sub(SEA_MONSTER_WIDTH) {
+                for y in 0..SEA_MONSTER_HEIGHT {
+                    for x in 0..SEA_MONSTER_WIDTH {
+                        if SEA_MONSTER[y][x] == b'#' && map[source(x0 + x, y0 + y)] == Pixel::Empty
+                        {
+                            continue 'check_sea_monster;
+                        }
+                    }
+                }
+
+                for y in 0..SEA_MONSTER_HEIGHT {
+                    for x in 0..SEA_MONSTER_WIDTH {
+                        if SEA_MONSTER[y][x] == b'#' {
+                            map[source(x0 + x, y0 + y)] = Pixel::SeaMonster;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(map
+        .values()
+        .filter(|pixel| **pixel == Pixel::Filled)
+        .count())
+}
+
+/// Assembles the image from the tiles of the input, in the orientation of an arbitrary tile.
+pub fn image(input: &str) -> Result<Grid<bool>> {
+    assemble_image(&Solution::parse(&Text::new(input))?)
+}
+
+pub fn part1(input: &str) -> Result<u64> {
+    corner_tile_ids_product(&Solution::parse(&Text::new(input))?)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    water_roughness(&Solution::parse(&Text::new(input))?)
+}
+
+/// Returns the canonical form of an edge, so that an edge and its reversal compare equal.
+fn canonical_edge(mut edge: Vec<bool>) -> Vec<bool> {
+    let reversed: Vec<bool> = edge.iter().rev().copied().collect();
+    if reversed < edge {
+        edge = reversed;
+    }
+    edge
+}
+
+/// Generates an image with a few sea monsters, cut into randomly transformed tiles, returning the
+/// image along with the input.
+fn generate_with_image(rng: &mut Rng) -> (Grid<bool>, String) {
+    let count = rng.range(2..5);
+    let size = (TILE_SIZE - 1) * count + 1;
+    let image_size = (TILE_SIZE - 2) * count;
+
+    // Neighboring tiles share their edges, which must only match each other
+    let mut pixels = loop {
+        let pixels = Grid::from_fn(size, size, |_| rng.chance(0.4));
+
+        let mut edges = HashSet::default();
+        let mut unique = true;
+        for a in 0..=count {
+            for b in 0..count {
+                for (x0, y0, dx, dy) in [(a, b, 0, 1), (b, a, 1, 0)] {
+                    let edge: Vec<bool> = (0..TILE_SIZE)
+                        .map(|i| {
+                            pixels[((TILE_SIZE - 1) * x0 + i * dx, (TILE_SIZE - 1) * y0 + i * dy)]
+                        })
+                        .collect();
+                    let edge = canonical_edge(edge);
+                    let reversed: Vec<bool> = edge.iter().rev().copied().collect();
+                    unique &= edge != reversed && edges.insert(edge);
+                }
+            }
+        }
+
+        if unique {
+            break pixels;
+        }
+    };
+
+    let image_position = |(x, y): (usize, usize)| {
+        let offset = |i: usize| (TILE_SIZE - 1) * (i / (TILE_SIZE - 2)) + 1 + i % (TILE_SIZE - 2);
+        (offset(x), offset(y))
+    };
+
+    if image_size >= SEA_MONSTER_WIDTH {
+        for _ in 0..rng.range(1..4) {
+            let x0 = rng.range(0..image_size - SEA_MONSTER_WIDTH + 1);
+            let y0 = rng.range(0..image_size - SEA_MONSTER_HEIGHT + 1);
+            for (y, row) in SEA_MONSTER.iter().enumerate() {
+                for (x, c) in row.iter().enumerate() {
+                    if *c == b'#' {
+                        pixels[image_position((x0 + x, y0 + y))] = true;
+                    }
+                }
+            }
+        }
+    }
+
+    let image = Grid::from_fn(image_size, image_size, |pos| pixels[image_position(pos)]);
+
+    let mut ids: Vec<TileId> = (1000..10000).collect();
+    rng.shuffle(&mut ids);
+
+    let mut chunks = Vec::new();
+    for ty in 0..count {
+        for tx in 0..count {
+            let tile = Grid::from_fn(TILE_SIZE, TILE_SIZE, |(x, y)| {
+                pixels[((TILE_SIZE - 1) * tx + x, (TILE_SIZE - 1) * ty + y)]
+            })
+            .transformed(*rng.choose(&TRANSFORMS));
+            let tile = Grid::from_fn(
+                TILE_SIZE,
+                TILE_SIZE,
+                |pos| {
+                    if tile[pos] {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                },
+            );
+            chunks.push(format!("Tile {}:\n{tile}", ids[chunks.len()]));
+        }
+    }
+    rng.shuffle(&mut chunks);
+
+    (image, chunks.join("\n"))
+}
+
+pub fn generate(rng: &mut Rng) -> String {
+    generate_with_image(rng).1
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Vec<(TileId, Tile)>;
+    type Answer1 = u64;
+    type Answer2 = usize;
+
+    const STRUCTURE: Structure = Structure::Blocks { lines: false };
+
+    fn parse<'a>(input: &'a Text) -> Result<Self::Input<'a>> {
+        parse_tiles(input)
+    }
+
+    fn part1(tiles: &Self::Input<'_>) -> Result<Self::Answer1> {
+        corner_tile_ids_product(tiles)
+    }
+
+    fn part2(tiles: &Self::Input<'_>) -> Option<Result<Self::Answer2>> {
+        Some(water_roughness(tiles))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::resaved;
+
+    static TEST_INPUT: &str = include_str!("test_input.txt");
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(part1(TEST_INPUT), Ok(20_899_048_083_289));
+    }
+
+    #[test]
+    fn part2_works() {
+        assert_eq!(part2(TEST_INPUT), Ok(273));
+    }
+
+    #[test]
+    fn resaved_input_works() {
+        for input in &resaved(TEST_INPUT) {
+            assert_eq!(part1(input), part1(TEST_INPUT));
+            assert_eq!(part2(input), part2(TEST_INPUT));
+        }
+    }
+
+    #[test]
+    fn invalid_input_is_reported() {
+        assert_eq!(
+            part1("Tile 1\n"),
+            Err(Error::parse(1, 1, "Expected `Tile <id>:`"))
+        );
+        assert_eq!(
+            part1("Tile 1:\n..........\n...\n"),
+            Err(Error::parse(3, 1, "Expected a row of 10 cells"))
+        );
+        assert_eq!(
+            part1("Tile 1:\n..........\n"),
+            Err(Error::parse(2, 1, "Expected 10 rows of 10 pixels"))
+        );
+    }
+
+    fn reference_corner_product(input: &str) -> u64 {
+        let tiles: Vec<(u64, Vec<Vec<bool>>)> = input
+            .split("\n\n")
+            .map(|chunk| {
+                let (header, body) = chunk.split_once('\n').unwrap();
+                let id = header[5..header.len() - 1].parse().unwrap();
+                let rows = body
+                    .lines()
+                    .map(|line| line.bytes().map(|b| b == b'#').collect());
+                (id, rows.collect())
+            })
+            .collect();
+
+        let edges = |rows: &Vec<Vec<bool>>| {
+            [
+                rows[0].clone(),
+                rows[TILE_SIZE - 1].clone(),
+                rows.iter().map(|row| row[0]).collect(),
+                rows.iter().map(|row| row[TILE_SIZE - 1]).collect(),
+            ]
+            .map(canonical_edge)
+        };
+
+        let mut counts = HashMap::default();
+        for (_, rows) in &tiles {
+            for edge in edges(rows) {
+                *counts.entry(edge).or_insert(0) += 1;
+            }
+        }
+
+        tiles
+            .iter()
+            .filter(|(_, rows)| edges(rows).iter().filter(|edge| counts[*edge] == 1).count() == 2)
+            .map(|(id, _)| id)
+            .product()
+    }
+
+    fn reference_roughness(image: &Grid<bool>) -> usize {
+        let mut monster = HashSet::default();
+
+        for transform in TRANSFORMS {
+            let view = image.view(transform);
+            let size = view.width();
+            for y0 in 0..(size + 1).saturating_sub(SEA_MONSTER_HEIGHT) {
+                for x0 in 0..(size + 1).saturating_sub(SEA_MONSTER_WIDTH) {
+                    let cells: Vec<_> = (0..SEA_MONSTER_HEIGHT)
+                        .flat_map(|y| (0..SEA_MONSTER_WIDTH).map(move |x| (x, y)))
+                        .filter(|(x, y)| SEA_MONSTER[*y][*x] == b'#')
+                        .map(|(x, y)| (x0 + x, y0 + y))
+                        .collect();
+                    if cells.iter().all(|pos| view[*pos]) {
+                        monster.extend(
+                            cells
+                                .iter()
+                                .map(|pos| transform.source_position(*pos, size, size)),
+                        );
+                    }
+                }
+            }
+        }
+
+        image.values().filter(|pixel| **pixel).count() - monster.len()
+    }
+
+    #[test]
+    fn random_inputs_match_reference() {
+        for seed in 0..20 {
+            let (image, input) = generate_with_image(&mut Rng::new(seed));
+            assert_eq!(
+                part1(&input),
+                Ok(reference_corner_product(&input)),
+                "seed {seed}"
+            );
+            assert_eq!(
+                part2(&input),
+                Ok(reference_roughness(&image)),
+                "seed {seed}"
+            );
+        }
+    }
+}
@@ -0,0 +1,378 @@
+use alloc::{borrow::Cow, format, string::String, vec::Vec};
+
+use itertools::Itertools;
+
+use crate::{
+    collections::{hash_map::Entry, HashMap, HashSet},
+    graph::Graph,
+    parser::Parser,
+    random::Rng,
+    solver::Solver,
+    text::Text,
+    Error, Result,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Food<'a> {
+    pub ingredients: HashSet<Cow<'a, str>>,
+    pub allergens: HashSet<Cow<'a, str>>,
+}
+
+impl<'a> Food<'a> {
+    fn parse(p: &mut Parser<'a>) -> Result<Self> {
+        let ingredients = p.separated(" ", Parser::word)?;
+        p.tag(" (contains ")?;
+        let allergens = p.separated(", ", Parser::word)?;
+        p.tag(")")?;
+
+        Ok(Self {
+            ingredients: ingredients.into_iter().map(Cow::Borrowed).collect(),
+            allergens: allergens.into_iter().map(Cow::Borrowed).collect(),
+        })
+    }
+}
+
+fn find_dangerous_ingredients<'a, 'b: 'a>(
+    foods: impl IntoIterator<Item = &'a Food<'b>>,
+) -> Option<impl Iterator<Item = &'a str>> {
+    let mut allergen_ingredients: HashMap<&str, HashSet<&str>> = HashMap::default();
+
+    // Intersect the sets of possible ingredients for each allergen
+    for food in foods.into_iter() {
+        for allergen in food.allergens.iter() {
+            match allergen_ingredients.entry(&**allergen) {
+                Entry::Vacant(entry) => {
+                    entry.insert(food.ingredients.iter().map(|i| &**i).collect());
+                }
+                Entry::Occupied(mut entry) => {
+                    entry
+                        .get_mut()
+                        .retain(|ingredient| food.ingredients.contains(*ingredient));
+
+                    // This shouldn't happen
+                    if entry.get().is_empty() {
+                        return None;
+                    }
+                }
+            }
+        }
+    }
+
+    let mut allergen_ingredients: Vec<(&str, HashSet<&str>)> =
+        allergen_ingredients.into_iter().collect();
+    allergen_ingredients.sort_unstable_by_key(|(allergen, _)| *allergen);
+
+    // Allergens are linked to the ingredients that may contain them, and then given distinct ones
+    let mut ingredients: Vec<&str> = Vec::new();
+    let mut ingredient_indices: HashMap<&str, usize> = HashMap::default();
+    for (_, candidates) in allergen_ingredients.iter() {
+        for ingredient in candidates {
+            ingredient_indices.entry(ingredient).or_insert_with(|| {
+                ingredients.push(ingredient);
+                ingredients.len() - 1
+            });
+        }
+    }
+
+    // The nodes of the allergens come first, followed by those of the ingredients
+    let allergen_count = allergen_ingredients.len();
+    let mut graph = Graph::new(allergen_count + ingredients.len());
+    for (allergen, (_, candidates)) in allergen_ingredients.iter().enumerate() {
+        for ingredient in candidates {
+            graph.add_edge(
+                allergen,
+                allergen_count + ingredient_indices[ingredient],
+                (),
+            );
+        }
+    }
+
+    let assignment = graph.assignment(0..allergen_count)?;
+    Some(
+        assignment
+            .into_iter()
+            .map(move |node| ingredients[node - allergen_count]),
+    )
+}
+
+fn count_safe_ingredients(foods: &[Food]) -> Result<usize> {
+    let all_ingredients: HashSet<&str> = foods
+        .iter()
+        .flat_map(|food| food.ingredients.iter().map(|ingredient| &**ingredient))
+        .collect();
+
+    let allergen_ingredients: HashSet<&str> = find_dangerous_ingredients(foods)
+        .ok_or(Error::NoSolution)?
+        .collect();
+
+    let safe_ingredients = all_ingredients
+        .into_iter()
+        .filter(|ingredient| !allergen_ingredients.contains(ingredient));
+
+    Ok(safe_ingredients
+        .map(|ingredient| {
+            foods
+                .iter()
+                .filter(|food| food.ingredients.contains(ingredient))
+                .count()
+        })
+        .sum())
+}
+
+fn canonical_dangerous_ingredients(foods: &[Food]) -> Result<String> {
+    let allergen_ingredients = find_dangerous_ingredients(foods).ok_or(Error::NoSolution)?;
+
+    Ok(allergen_ingredients.collect::<Vec<_>>().join(","))
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    count_safe_ingredients(&Solution::parse(&Text::new(input))?)
+}
+
+pub fn part2(input: &str) -> Result<String> {
+    canonical_dangerous_ingredients(&Solution::parse(&Text::new(input))?)
+}
+
+const ALLERGENS: [&str; 8] = [
+    "dairy", "eggs", "fish", "nuts", "peanuts", "sesame", "soy", "wheat",
+];
+
+/// Counts the ways to give each remaining allergen a distinct ingredient from its candidates,
+/// stopping early once there is more than one.
+fn count_allergen_assignments(candidates: &[Vec<usize>], assigned: &mut Vec<usize>) -> usize {
+    let Some(ingredients) = candidates.get(assigned.len()) else {
+        return 1;
+    };
+
+    let mut count = 0;
+    for ingredient in ingredients {
+        if !assigned.contains(ingredient) {
+            assigned.push(*ingredient);
+            count += count_allergen_assignments(candidates, assigned);
+            assigned.pop();
+            if count > 1 {
+                break;
+            }
+        }
+    }
+    count
+}
+
+/// Generates foods where each allergen is in a distinct ingredient, with labels that only allow
+/// one assignment.
+pub fn generate(rng: &mut Rng) -> String {
+    let ingredients: Vec<String> = (0..rng.range(4..15))
+        .map(|index| {
+            let name: String = (0..rng.range(3..6))
+                .map(|_| *rng.choose(&['a', 'b', 'c', 'd', 'f', 'g', 'k', 'l', 'm', 'x']))
+                .collect();
+            format!("{name}{index}")
+        })
+        .collect();
+
+    let mut allergens = ALLERGENS.to_vec();
+    rng.shuffle(&mut allergens);
+    allergens.truncate(rng.range(1..5.min(ingredients.len())));
+
+    let mut sources: Vec<usize> = (0..ingredients.len()).collect();
+    rng.shuffle(&mut sources);
+
+    loop {
+        let foods: Vec<(Vec<usize>, Vec<usize>)> = (0..rng.range(2..12))
+            .map(|_| {
+                let mut contained: Vec<usize> =
+                    (0..allergens.len()).filter(|_| rng.chance(0.4)).collect();
+                if contained.is_empty() {
+                    contained.push(rng.range(0..allergens.len()));
+                }
+
+                let mut food: Vec<usize> = (0..ingredients.len())
+                    .filter(|ingredient| {
+                        contained
+                            .iter()
+                            .any(|allergen| sources[*allergen] == *ingredient)
+                    })
+                    .collect();
+                for ingredient in 0..ingredients.len() {
+                    if !food.contains(&ingredient) && rng.chance(0.5) {
+                        food.push(ingredient);
+                    }
+                }
+                rng.shuffle(&mut food);
+
+                (food, contained)
+            })
+            .collect();
+
+        let candidates: Vec<Vec<usize>> = (0..allergens.len())
+            .map(|allergen| {
+                (0..ingredients.len())
+                    .filter(|ingredient| {
+                        foods.iter().all(|(food, contained)| {
+                            !contained.contains(&allergen) || food.contains(ingredient)
+                        })
+                    })
+                    .collect()
+            })
+            .collect();
+
+        if count_allergen_assignments(&candidates, &mut Vec::new()) == 1 {
+            return foods
+                .iter()
+                .map(|(food, contained)| {
+                    format!(
+                        "{} (contains {})",
+                        food.iter().map(|index| &ingredients[*index]).join(" "),
+                        contained.iter().map(|index| allergens[*index]).join(", ")
+                    )
+                })
+                .join("\n");
+        }
+    }
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Vec<Food<'a>>;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse<'a>(input: &'a Text) -> Result<Self::Input<'a>> {
+        input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let mut p = Parser::within(input, line);
+                let food = Food::parse(&mut p)?;
+                p.end()?;
+                Ok(food)
+            })
+            .collect()
+    }
+
+    fn part1(foods: &Self::Input<'_>) -> Result<Self::Answer1> {
+        count_safe_ingredients(foods)
+    }
+
+    fn part2(foods: &Self::Input<'_>) -> Option<Result<Self::Answer2>> {
+        Some(canonical_dangerous_ingredients(foods))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::resaved;
+
+    static TEST_INPUT: &str = include_str!("test_input.txt");
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(part1(TEST_INPUT), Ok(5));
+    }
+
+    #[test]
+    fn part2_works() {
+        assert_eq!(part2(TEST_INPUT), Ok("mxmxvkd,sqjhc,fvjkl".into()));
+    }
+
+    #[test]
+    fn resaved_input_works() {
+        for input in &resaved(TEST_INPUT) {
+            assert_eq!(part1(input), part1(TEST_INPUT));
+            assert_eq!(part2(input), part2(TEST_INPUT));
+        }
+    }
+
+    #[test]
+    fn invalid_input_is_reported() {
+        assert_eq!(
+            part1("kfcds sqjhc (contains fish)\nmxmxvkd kfcds (contains dairy,fish)"),
+            Err(Error::parse(2, 30, "Expected `)`"))
+        );
+        assert_eq!(
+            part1("kfcds sqjhc (contains fish) mxmxvkd"),
+            Err(Error::parse(1, 28, "Unexpected ` mxmxvkd`"))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn foods_round_trip_through_json() {
+        let text = Text::new(TEST_INPUT);
+        let foods = Solution::parse(&text).unwrap();
+        let json = serde_json::to_string(&foods).unwrap();
+        assert_eq!(serde_json::from_str::<Vec<Food>>(&json).unwrap(), foods);
+
+        let json = r#"[{"ingredients": ["a", "b"], "allergens": ["fish"]}, {"ingredients": ["\u0062"], "allergens": ["fish"]}]"#;
+        assert_eq!(Solution::part1(&serde_json::from_str(json).unwrap()), Ok(1));
+    }
+
+    /// Tries every assignment of distinct ingredients to allergens, returning the only one that
+    /// agrees with the labels.
+    fn reference_assignment(input: &str) -> Vec<(&str, &str)> {
+        let foods: Vec<(Vec<&str>, Vec<&str>)> = input
+            .lines()
+            .map(|line| {
+                let (ingredients, allergens) = line.split_once(" (contains ").unwrap();
+                let allergens = allergens.trim_end_matches(')');
+                (
+                    ingredients.split(' ').collect(),
+                    allergens.split(", ").collect(),
+                )
+            })
+            .collect();
+
+        let mut ingredients: Vec<&str> = foods
+            .iter()
+            .flat_map(|(ingredients, _)| ingredients.iter().copied())
+            .collect();
+        ingredients.sort_unstable();
+        ingredients.dedup();
+        let mut allergens: Vec<&str> = foods
+            .iter()
+            .flat_map(|(_, allergens)| allergens.iter().copied())
+            .collect();
+        allergens.sort_unstable();
+        allergens.dedup();
+
+        let mut assignments = ingredients
+            .iter()
+            .copied()
+            .permutations(allergens.len())
+            .filter(|sources| {
+                foods.iter().all(|(food, contained)| {
+                    allergens.iter().zip(sources).all(|(allergen, source)| {
+                        !contained.contains(allergen) || food.contains(source)
+                    })
+                })
+            });
+
+        let sources = assignments.next().unwrap();
+        assert!(assignments.next().is_none());
+        allergens.into_iter().zip(sources).collect()
+    }
+
+    #[test]
+    fn random_inputs_match_reference() {
+        for seed in 0..100 {
+            let input = generate(&mut Rng::new(seed));
+            let assignment = reference_assignment(&input);
+
+            let safe_count = input
+                .lines()
+                .flat_map(|line| line.split(" (").next().unwrap().split(' '))
+                .filter(|ingredient| assignment.iter().all(|(_, source)| source != ingredient))
+                .count();
+
+            assert_eq!(part1(&input), Ok(safe_count), "seed {seed}");
+            assert_eq!(
+                part2(&input),
+                Ok(assignment.iter().map(|(_, source)| source).join(",")),
+                "seed {seed}"
+            );
+        }
+    }
+}
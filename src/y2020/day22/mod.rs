@@ -0,0 +1,453 @@
+use alloc::{collections::VecDeque, format, string::String, vec::Vec};
+use core::{cmp::Ordering, mem};
+
+use itertools::Itertools;
+
+use crate::{
+    collections::HashSet,
+    error::parse_at,
+    observer::{notify, Ignore, Observer},
+    random::Rng,
+    reduce::Structure,
+    solver::Solver,
+    text::Text,
+    Error, Result,
+};
+
+pub type Card = usize;
+/// The cards of a player, from top to bottom.
+pub type Deck = VecDeque<Card>;
+
+fn parse_deck(input: &str, chunk: &str) -> Result<Deck> {
+    let mut lines = chunk.lines();
+
+    match lines.next() {
+        Some(line) if line.starts_with("Player ") && line.ends_with(':') => {}
+        _ => return Err(Error::at(input, chunk, "Expected `Player <number>:`")),
+    }
+
+    lines.map(|line| parse_at(input, line)).collect()
+}
+
+fn parse_decks(input: &str) -> Result<(Deck, Deck)> {
+    let mut it = input
+        .split("\n\n")
+        .filter(|chunk| !chunk.trim().is_empty())
+        .map(|chunk| parse_deck(input, chunk));
+
+    let first = it
+        .next()
+        .ok_or_else(|| Error::at_end(input, "Expected a deck for player 1"))??;
+    let second = it
+        .next()
+        .ok_or_else(|| Error::at_end(input, "Expected a deck for player 2"))??;
+
+    Ok((first, second))
+}
+
+enum GameResult {
+    Player1(Deck),
+    Player2(Deck),
+}
+
+impl GameResult {
+    fn deck(&self) -> &Deck {
+        match self {
+            Self::Player1(deck) => deck,
+            Self::Player2(deck) => deck,
+        }
+    }
+
+    pub fn score(&self) -> Result<usize> {
+        self.deck()
+            .iter()
+            .rev()
+            .zip(1usize..)
+            .try_fold(0usize, |score, (card, i)| {
+                score.checked_add(i.checked_mul(*card)?)
+            })
+            .ok_or(Error::NoSolution)
+    }
+}
+
+/// Plays a game of Combat, passing the decks to `observer` after each round. Fails if the decks
+/// repeat, since the game then goes on forever.
+fn play_game(
+    decks: &(Deck, Deck),
+    observer: &mut impl Observer<(Deck, Deck)>,
+) -> Result<GameResult> {
+    let mut decks = decks.clone();
+    let mut seen_decks = HashSet::default();
+
+    loop {
+        if !seen_decks.insert(decks.clone()) {
+            break Err(Error::NoSolution);
+        }
+
+        let (deck1, deck2) = &mut decks;
+
+        if !deck1.is_empty() && deck2.is_empty() {
+            break Ok(GameResult::Player1(mem::take(deck1)));
+        } else if deck1.is_empty() && !deck2.is_empty() {
+            break Ok(GameResult::Player2(mem::take(deck2)));
+        }
+
+        let card1 = deck1.pop_front().ok_or(Error::NoSolution)?;
+        let card2 = deck2.pop_front().ok_or(Error::NoSolution)?;
+
+        match card1.cmp(&card2) {
+            Ordering::Equal => {
+                break Err(Error::NoSolution);
+            }
+            Ordering::Greater => {
+                deck1.push_back(card1);
+                deck1.push_back(card2);
+            }
+            Ordering::Less => {
+                deck2.push_back(card2);
+                deck2.push_back(card1);
+            }
+        }
+
+        notify(observer, &decks)?;
+    }
+}
+
+/// A game of Recursive Combat, as passed to observers after each of its rounds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecursiveGame {
+    /// The number of games that this one is played within, which is zero for the outermost one.
+    pub depth: usize,
+    pub decks: (Deck, Deck),
+}
+
+/// Plays a game of Recursive Combat, passing it to `observer` after each round, including the
+/// rounds of the games played within it.
+fn play_recursive_game(
+    mut game: RecursiveGame,
+    observer: &mut impl Observer<RecursiveGame>,
+) -> Result<GameResult> {
+    let mut seen_decks = HashSet::default();
+
+    loop {
+        let repeated = !seen_decks.insert(game.decks.clone());
+        let (deck1, deck2) = &mut game.decks;
+
+        if repeated || !deck1.is_empty() && deck2.is_empty() {
+            break Ok(GameResult::Player1(mem::take(deck1)));
+        } else if deck1.is_empty() && !deck2.is_empty() {
+            break Ok(GameResult::Player2(mem::take(deck2)));
+        }
+
+        let card1 = deck1.pop_front().ok_or(Error::NoSolution)?;
+        let card2 = deck2.pop_front().ok_or(Error::NoSolution)?;
+
+        if deck1.len() >= card1 && deck2.len() >= card2 {
+            let sub_game = RecursiveGame {
+                depth: game.depth + 1,
+                decks: (
+                    deck1.iter().take(card1).copied().collect(),
+                    deck2.iter().take(card2).copied().collect(),
+                ),
+            };
+            match play_recursive_game(sub_game, observer)? {
+                GameResult::Player1(_) => {
+                    deck1.push_back(card1);
+                    deck1.push_back(card2);
+                }
+                GameResult::Player2(_) => {
+                    deck2.push_back(card2);
+                    deck2.push_back(card1);
+                }
+            }
+        } else {
+            match card1.cmp(&card2) {
+                Ordering::Equal => {
+                    break Err(Error::NoSolution);
+                }
+                Ordering::Greater => {
+                    deck1.push_back(card1);
+                    deck1.push_back(card2);
+                }
+                Ordering::Less => {
+                    deck2.push_back(card2);
+                    deck2.push_back(card1);
+                }
+            }
+        }
+
+        notify(observer, &game)?;
+    }
+}
+
+fn winning_score(
+    decks: &(Deck, Deck),
+    observer: &mut impl Observer<(Deck, Deck)>,
+) -> Result<usize> {
+    play_game(decks, observer)?.score()
+}
+
+fn winning_recursive_score(
+    decks: &(Deck, Deck),
+    observer: &mut impl Observer<RecursiveGame>,
+) -> Result<usize> {
+    let game = RecursiveGame {
+        depth: 0,
+        decks: decks.clone(),
+    };
+
+    play_recursive_game(game, observer)?.score()
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    part1_observed(input, &mut Ignore)
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    part2_observed(input, &mut Ignore)
+}
+
+/// Like [`part1`], but passes the decks to `observer` after each round.
+pub fn part1_observed(input: &str, observer: &mut impl Observer<(Deck, Deck)>) -> Result<usize> {
+    winning_score(&Solution::parse(&Text::new(input))?, observer)
+}
+
+/// Like [`part2`], but passes the game to `observer` after each round, including the rounds of
+/// the games played within it.
+pub fn part2_observed(input: &str, observer: &mut impl Observer<RecursiveGame>) -> Result<usize> {
+    winning_recursive_score(&Solution::parse(&Text::new(input))?, observer)
+}
+
+/// Returns whether a game of Combat between the decks ever repeats a position, which would make
+/// it go on forever.
+fn combat_repeats(mut deck1: Deck, mut deck2: Deck) -> bool {
+    let mut seen = HashSet::default();
+
+    while !deck1.is_empty() && !deck2.is_empty() {
+        if !seen.insert((deck1.clone(), deck2.clone())) {
+            return true;
+        }
+
+        let (card1, card2) = (deck1.pop_front().unwrap(), deck2.pop_front().unwrap());
+        if card1 > card2 {
+            deck1.extend([card1, card2]);
+        } else {
+            deck2.extend([card2, card1]);
+        }
+    }
+    false
+}
+
+/// Generates two equally sized decks that share out distinct cards, for games of Combat that end.
+pub fn generate(rng: &mut Rng) -> String {
+    let size = rng.range(1..13);
+
+    loop {
+        let mut cards: Vec<Card> = (1..=2 * size).collect();
+        rng.shuffle(&mut cards);
+        let (deck1, deck2) = cards.split_at(size);
+
+        if !combat_repeats(
+            deck1.iter().copied().collect(),
+            deck2.iter().copied().collect(),
+        ) {
+            return format!(
+                "Player 1:\n{}\n\nPlayer 2:\n{}",
+                deck1.iter().join("\n"),
+                deck2.iter().join("\n")
+            );
+        }
+    }
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = (Deck, Deck);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const STRUCTURE: Structure = Structure::Sections;
+
+    fn parse<'a>(input: &'a Text) -> Result<Self::Input<'a>> {
+        parse_decks(input)
+    }
+
+    fn part1(decks: &Self::Input<'_>) -> Result<Self::Answer1> {
+        winning_score(decks, &mut Ignore)
+    }
+
+    fn part2(decks: &Self::Input<'_>) -> Option<Result<Self::Answer2>> {
+        Some(winning_recursive_score(decks, &mut Ignore))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::observer::Trace;
+    use crate::text::resaved;
+    use std::ops::ControlFlow;
+
+    static TEST_INPUT: &str = include_str!("test_input.txt");
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(part1(TEST_INPUT), Ok(306));
+    }
+
+    #[test]
+    fn part2_works() {
+        assert_eq!(part2(TEST_INPUT), Ok(291));
+    }
+
+    #[test]
+    fn resaved_input_works() {
+        for input in &resaved(TEST_INPUT) {
+            assert_eq!(part1(input), part1(TEST_INPUT));
+            assert_eq!(part2(input), part2(TEST_INPUT));
+        }
+    }
+
+    #[test]
+    fn endless_combat_has_no_solution() {
+        assert_eq!(
+            part1("Player 1:\n43\n19\n\nPlayer 2:\n2\n29\n14"),
+            Err(Error::NoSolution)
+        );
+    }
+
+    #[test]
+    fn observer_sees_each_round() {
+        let mut trace = Trace::new();
+        assert_eq!(part1_observed(TEST_INPUT, &mut trace), Ok(306));
+        assert_eq!(trace.states.len(), 29);
+        assert_eq!(
+            trace.states[0],
+            ([2, 6, 3, 1, 9, 5].into(), [8, 4, 7, 10].into())
+        );
+        assert_eq!(
+            trace.states[28],
+            ([].into(), [3, 2, 10, 6, 8, 5, 9, 4, 7, 1].into())
+        );
+
+        let mut stop_on_empty_deck = |(deck1, deck2): &(Deck, Deck)| {
+            if deck1.is_empty() || deck2.is_empty() {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        };
+        assert_eq!(
+            part1_observed(TEST_INPUT, &mut stop_on_empty_deck),
+            Err(Error::Stopped)
+        );
+    }
+
+    #[test]
+    fn observer_sees_each_round_of_every_game() {
+        let mut trace = Trace::new();
+        assert_eq!(part2_observed(TEST_INPUT, &mut trace), Ok(291));
+        let depths: Vec<usize> = trace.states.iter().map(|game| game.depth).collect();
+        assert_eq!(
+            depths,
+            [
+                0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 0, 0, 0, 0, 1, 2, 1, 1, 1, 0, 0, 1, 0, 0,
+                0
+            ]
+        );
+        assert_eq!(depths.iter().filter(|depth| **depth == 0).count(), 17);
+        assert_eq!(
+            trace.states[28].decks,
+            ([].into(), [7, 5, 6, 2, 4, 1, 10, 8, 9, 3].into())
+        );
+
+        let mut stop_in_sub_games = |game: &RecursiveGame| {
+            if game.depth > 0 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        };
+        assert_eq!(
+            part2_observed(TEST_INPUT, &mut stop_in_sub_games),
+            Err(Error::Stopped)
+        );
+    }
+
+    /// Plays a game of Recursive Combat, returning whether player 1 won along with the winning
+    /// deck, remembering exact positions rather than hashes.
+    fn reference_recursive_game(
+        mut deck1: Vec<usize>,
+        mut deck2: Vec<usize>,
+    ) -> (bool, Vec<usize>) {
+        let mut seen = Vec::new();
+
+        while !deck1.is_empty() && !deck2.is_empty() {
+            if seen.contains(&(deck1.clone(), deck2.clone())) {
+                return (true, deck1);
+            }
+            seen.push((deck1.clone(), deck2.clone()));
+
+            let (card1, card2) = (deck1.remove(0), deck2.remove(0));
+            let player1_wins = if deck1.len() >= card1 && deck2.len() >= card2 {
+                reference_recursive_game(deck1[..card1].to_vec(), deck2[..card2].to_vec()).0
+            } else {
+                card1 > card2
+            };
+
+            if player1_wins {
+                deck1.extend([card1, card2]);
+            } else {
+                deck2.extend([card2, card1]);
+            }
+        }
+
+        if deck2.is_empty() {
+            (true, deck1)
+        } else {
+            (false, deck2)
+        }
+    }
+
+    fn reference_score(deck: &[usize]) -> usize {
+        deck.iter()
+            .rev()
+            .enumerate()
+            .map(|(index, card)| (index + 1) * card)
+            .sum()
+    }
+
+    #[test]
+    fn random_inputs_match_reference() {
+        for seed in 0..100 {
+            let input = generate(&mut Rng::new(seed));
+            let (deck1, deck2) = input.split_once("\n\n").unwrap();
+            let parse_deck = |deck: &str| -> Vec<usize> {
+                deck.lines()
+                    .skip(1)
+                    .map(|line| line.parse().unwrap())
+                    .collect()
+            };
+            let (deck1, deck2) = (parse_deck(deck1), parse_deck(deck2));
+
+            let (mut combat1, mut combat2) = (deck1.clone(), deck2.clone());
+            while !combat1.is_empty() && !combat2.is_empty() {
+                let (card1, card2) = (combat1.remove(0), combat2.remove(0));
+                if card1 > card2 {
+                    combat1.extend([card1, card2]);
+                } else {
+                    combat2.extend([card2, card1]);
+                }
+            }
+            let winner = if combat1.is_empty() { combat2 } else { combat1 };
+
+            assert_eq!(part1(&input), Ok(reference_score(&winner)), "seed {seed}");
+            assert_eq!(
+                part2(&input),
+                Ok(reference_score(&reference_recursive_game(deck1, deck2).1)),
+                "seed {seed}"
+            );
+        }
+    }
+}
@@ -0,0 +1,400 @@
+use alloc::{boxed::Box, format, string::String, vec, vec::Vec};
+use core::iter;
+
+use itertools::Itertools;
+
+use crate::{
+    observer::{notify, Ignore, Observer},
+    random::Rng,
+    solver::Solver,
+    text::Text,
+    Error, Result,
+};
+
+/// A cup, numbered from 0 rather than labeled from 1.
+pub type Cup = usize;
+
+const MIN_CUPS: usize = 5;
+
+/// Puzzle constants, defaulting to those of the actual puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    /// The number of moves in part 1.
+    pub part1_moves: usize,
+    /// The number of cups in part 2, if more than the labeled cups.
+    pub part2_cups: usize,
+    /// The number of moves in part 2.
+    pub part2_moves: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            part1_moves: 100,
+            part2_cups: 1_000_000,
+            part2_moves: 10_000_000,
+        }
+    }
+}
+
+fn parse_cups(input: &str) -> Result<Vec<Cup>> {
+    let line = input.trim_end();
+    let mut cups = Vec::with_capacity(line.len());
+
+    for (index, c) in line.char_indices() {
+        let label = c.to_digit(10).unwrap_or(0) as usize;
+        if label == 0 || label > line.len() || cups.contains(&(label - 1)) {
+            return Err(Error::at(
+                input,
+                &line[index..],
+                format!("Expected each cup from 1 to {} exactly once", line.len()),
+            ));
+        }
+        cups.push(label - 1);
+    }
+
+    if cups.len() < MIN_CUPS {
+        return Err(Error::at(
+            input,
+            &line[line.len()..],
+            format!("Expected at least {MIN_CUPS} cups"),
+        ));
+    }
+
+    Ok(cups)
+}
+
+/// The circle of cups, as the cup clockwise of each cup.
+#[derive(Debug, Clone)]
+pub struct Game {
+    pub current_cup: Cup,
+    pub next_cups: Box<[Cup]>,
+}
+
+impl Game {
+    pub fn new(initial_cups: &[Cup], cups_count: usize) -> Self {
+        let mut cups = Vec::with_capacity(cups_count);
+
+        cups.extend_from_slice(initial_cups);
+        cups.extend(initial_cups.len()..cups_count);
+
+        let mut next_cups = vec![0; cups_count];
+
+        for i in 0..cups.len() {
+            let cup = cups[i];
+            let next_cup = cups[(i + 1) % cups.len()];
+            next_cups[cup] = next_cup;
+        }
+
+        Self {
+            current_cup: initial_cups[0],
+            next_cups: next_cups.into(),
+        }
+    }
+
+    /// The labels of the cups in clockwise order, starting from the current cup.
+    pub fn labels(&self) -> impl Iterator<Item = usize> + '_ {
+        iter::successors(Some(self.current_cup), |cup| Some(self.next_cups[*cup]))
+            .take(self.next_cups.len())
+            .map(|cup| cup + 1)
+    }
+
+    #[inline]
+    pub fn make_move(&mut self) {
+        let picked_cup_0 = self.next_cups[self.current_cup];
+        let picked_cup_1 = self.next_cups[picked_cup_0];
+        let picked_cup_2 = self.next_cups[picked_cup_1];
+
+        let next_cup = self.next_cups[picked_cup_2];
+        self.next_cups[self.current_cup] = next_cup;
+
+        let destination_cup = {
+            let len = self.next_cups.len();
+            let mut d = self.current_cup;
+            loop {
+                d = (d + len - 1) % len;
+                if d != picked_cup_0 && d != picked_cup_1 && d != picked_cup_2 {
+                    break d;
+                }
+            }
+        };
+
+        self.next_cups[picked_cup_2] = self.next_cups[destination_cup];
+        self.next_cups[destination_cup] = picked_cup_0;
+
+        self.current_cup = next_cup;
+    }
+}
+
+/// Plays the game, passing it to `observer` after each move.
+fn play(mut game: Game, moves: usize, observer: &mut impl Observer<Game>) -> Result<Game> {
+    for _ in 0..moves {
+        game.make_move();
+        notify(observer, &game)?;
+    }
+
+    Ok(game)
+}
+
+fn labels_after_cup_1(
+    initial_cups: &[Cup],
+    moves: usize,
+    observer: &mut impl Observer<Game>,
+) -> Result<String> {
+    let game = play(Game::new(initial_cups, initial_cups.len()), moves, observer)?;
+
+    use core::fmt::Write;
+
+    let mut stringified = String::with_capacity(game.next_cups.len() - 1);
+    let mut current_cup = game.next_cups[0];
+
+    for _ in 0..game.next_cups.len() - 1 {
+        write!(&mut stringified, "{}", current_cup + 1).unwrap();
+        current_cup = game.next_cups[current_cup];
+    }
+
+    Ok(stringified)
+}
+
+fn star_cups_product(
+    initial_cups: &[Cup],
+    cups_count: usize,
+    moves: usize,
+    observer: &mut impl Observer<Game>,
+) -> Result<u64> {
+    let game = Game::new(initial_cups, cups_count.max(initial_cups.len()));
+    let game = play(game, moves, observer)?;
+
+    let cup1 = game.next_cups[0];
+    let cup2 = game.next_cups[cup1];
+
+    Ok(((cup1 as u64) + 1) * ((cup2 as u64) + 1))
+}
+
+pub fn part1(input: &str, moves: usize) -> Result<String> {
+    labels_after_cup_1(&Solution::parse(&Text::new(input))?, moves, &mut Ignore)
+}
+
+pub fn part2(input: &str) -> Result<u64> {
+    part2_with(input, &Params::default())
+}
+
+pub fn part1_with(input: &str, params: &Params) -> Result<String> {
+    part1(input, params.part1_moves)
+}
+
+pub fn part2_with(input: &str, params: &Params) -> Result<u64> {
+    part2_observed(input, params, &mut Ignore)
+}
+
+/// Like [`part1_with`], but passes the game to `observer` after each move.
+pub fn part1_observed(
+    input: &str,
+    params: &Params,
+    observer: &mut impl Observer<Game>,
+) -> Result<String> {
+    labels_after_cup_1(
+        &Solution::parse(&Text::new(input))?,
+        params.part1_moves,
+        observer,
+    )
+}
+
+/// Like [`part2_with`], but passes the game to `observer` after each move.
+pub fn part2_observed(
+    input: &str,
+    params: &Params,
+    observer: &mut impl Observer<Game>,
+) -> Result<u64> {
+    star_cups_product(
+        &Solution::parse(&Text::new(input))?,
+        params.part2_cups,
+        params.part2_moves,
+        observer,
+    )
+}
+
+/// Generates a random arrangement of between 5 and 9 cups.
+pub fn generate(rng: &mut Rng) -> String {
+    let mut labels: Vec<usize> = (1..=rng.range(MIN_CUPS..10)).collect();
+    rng.shuffle(&mut labels);
+    labels.into_iter().join("")
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Vec<Cup>;
+    type Answer1 = String;
+    type Answer2 = u64;
+
+    fn parse<'a>(input: &'a Text) -> Result<Self::Input<'a>> {
+        parse_cups(input)
+    }
+
+    fn part1(initial_cups: &Self::Input<'_>) -> Result<Self::Answer1> {
+        let params = Params::default();
+        labels_after_cup_1(initial_cups, params.part1_moves, &mut Ignore)
+    }
+
+    fn part2(initial_cups: &Self::Input<'_>) -> Option<Result<Self::Answer2>> {
+        let params = Params::default();
+        Some(star_cups_product(
+            initial_cups,
+            params.part2_cups,
+            params.part2_moves,
+            &mut Ignore,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::observer::Trace;
+    use crate::text::resaved;
+    use std::ops::ControlFlow;
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(part1("389125467", 10), Ok("92658374".into()));
+        assert_eq!(part1("389125467", 100), Ok("67384529".into()));
+    }
+
+    #[test]
+    fn part2_works() {
+        assert_eq!(part2("389125467"), Ok(149_245_887_792));
+    }
+
+    #[test]
+    fn resaved_input_works() {
+        for input in &resaved("389125467") {
+            assert_eq!(part1(input, 100), part1("389125467", 100));
+        }
+    }
+
+    #[test]
+    fn params_change_the_game() {
+        let params = Params {
+            part1_moves: 10,
+            part2_cups: 9,
+            part2_moves: 10,
+        };
+        assert_eq!(part1_with("389125467", &params), Ok("92658374".into()));
+        assert_eq!(part2_with("389125467", &params), Ok(9 * 2));
+    }
+
+    #[test]
+    fn observer_sees_each_move() {
+        let params = Params {
+            part1_moves: 10,
+            ..Params::default()
+        };
+        let mut trace = Trace::new();
+        assert_eq!(
+            part1_observed("389125467", &params, &mut trace),
+            Ok("92658374".into())
+        );
+        assert_eq!(trace.states.len(), 10);
+        assert_eq!(
+            trace.states[0].labels().collect_vec(),
+            [2, 8, 9, 1, 5, 4, 6, 7, 3]
+        );
+        assert_eq!(
+            trace.states[9].labels().collect_vec(),
+            [8, 3, 7, 4, 1, 9, 2, 6, 5]
+        );
+
+        let mut moves = 0;
+        let mut stop_after_1000 = |_: &Game| {
+            moves += 1;
+            if moves < 1000 {
+                ControlFlow::Continue(())
+            } else {
+                ControlFlow::Break(())
+            }
+        };
+        assert_eq!(
+            part2_observed("389125467", &Params::default(), &mut stop_after_1000),
+            Err(Error::Stopped)
+        );
+        assert_eq!(moves, 1000);
+    }
+
+    #[test]
+    fn invalid_input_is_reported() {
+        assert_eq!(
+            part1("38912x467", 10),
+            Err(Error::parse(
+                1,
+                6,
+                "Expected each cup from 1 to 9 exactly once"
+            ))
+        );
+        assert_eq!(
+            part1("31244", 10),
+            Err(Error::parse(
+                1,
+                5,
+                "Expected each cup from 1 to 5 exactly once"
+            ))
+        );
+        assert_eq!(
+            part1("312", 10),
+            Err(Error::parse(1, 4, "Expected at least 5 cups"))
+        );
+    }
+
+    /// Plays the game on a list of cups that always starts with the current cup.
+    fn reference_cups(initial_cups: &[Cup], cups_count: usize, moves: usize) -> Vec<Cup> {
+        let mut cups: Vec<Cup> = initial_cups.to_vec();
+        cups.extend(initial_cups.len()..cups_count);
+
+        for _ in 0..moves {
+            let picked: Vec<Cup> = cups.drain(1..4).collect();
+            let mut destination = cups[0];
+            loop {
+                destination = (destination + cups_count - 1) % cups_count;
+                if !picked.contains(&destination) {
+                    break;
+                }
+            }
+            let index = cups.iter().position(|cup| *cup == destination).unwrap();
+            cups.splice(index + 1..index + 1, picked);
+            cups.rotate_left(1);
+        }
+
+        let index = cups.iter().position(|cup| *cup == 0).unwrap();
+        cups.rotate_left(index);
+        cups
+    }
+
+    #[test]
+    fn random_inputs_match_reference() {
+        for seed in 0..100 {
+            let mut rng = Rng::new(seed);
+            let input = generate(&mut rng);
+            let moves = rng.range(0..200);
+            let initial_cups = parse_cups(&input).unwrap();
+
+            let expected: String = reference_cups(&initial_cups, initial_cups.len(), moves)[1..]
+                .iter()
+                .map(|cup| cup + 1)
+                .join("");
+            assert_eq!(part1(&input, moves), Ok(expected), "seed {seed}");
+
+            // Part 2 plays the same game with many more cups
+            let cups_count = rng.range(initial_cups.len()..50);
+            let expected = reference_cups(&initial_cups, cups_count, moves);
+            let mut game = Game::new(&initial_cups, cups_count);
+            for _ in 0..moves {
+                game.make_move();
+            }
+            let mut cups = vec![0];
+            while cups.len() < cups_count {
+                cups.push(game.next_cups[*cups.last().unwrap()]);
+            }
+            assert_eq!(cups, expected, "seed {seed}");
+        }
+    }
+}
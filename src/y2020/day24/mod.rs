@@ -0,0 +1,428 @@
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "std")]
+use std::io::BufRead;
+
+use itertools::Itertools;
+
+#[cfg(feature = "std")]
+use crate::stream::Lines;
+use crate::{
+    automaton::Sparse,
+    collections::HashSet,
+    observer::{notify, Ignore, Observer},
+    random::Rng,
+    solver::Solver,
+    text::Text,
+    Error, Result,
+};
+
+/// Axial coordinates of a hexagonal tile, wide enough for any line to stay in range.
+pub type Position = (i64, i64);
+
+const DAYS: usize = 100;
+
+/// Puzzle constants, defaulting to those of the actual puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    /// The number of days to live the tiles for in part 2.
+    pub days: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params { days: DAYS }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Direction {
+    East,
+    West,
+    SouthEast,
+    SouthWest,
+    NorthEast,
+    NorthWest,
+}
+
+const ALL_DIRECTIONS: [Direction; 6] = [
+    Direction::East,
+    Direction::West,
+    Direction::SouthEast,
+    Direction::SouthWest,
+    Direction::NorthEast,
+    Direction::NorthWest,
+];
+
+impl Direction {
+    pub fn step(self, (x, y): Position) -> Position {
+        match self {
+            Self::East => (x + 1, y),
+            Self::West => (x - 1, y),
+            Self::SouthEast => (x, y + 1),
+            Self::SouthWest => (x - 1, y + 1),
+            Self::NorthEast => (x + 1, y - 1),
+            Self::NorthWest => (x, y - 1),
+        }
+    }
+}
+
+fn parse_line(input: &str, mut line: &str) -> Result<Position> {
+    let mut pos = (0, 0);
+
+    while !line.is_empty() {
+        let dir = if let Some(remaining_line) = line.strip_prefix('e') {
+            line = remaining_line;
+            Direction::East
+        } else if let Some(remaining_line) = line.strip_prefix('w') {
+            line = remaining_line;
+            Direction::West
+        } else if let Some(remaining_line) = line.strip_prefix("se") {
+            line = remaining_line;
+            Direction::SouthEast
+        } else if let Some(remaining_line) = line.strip_prefix("sw") {
+            line = remaining_line;
+            Direction::SouthWest
+        } else if let Some(remaining_line) = line.strip_prefix("ne") {
+            line = remaining_line;
+            Direction::NorthEast
+        } else if let Some(remaining_line) = line.strip_prefix("nw") {
+            line = remaining_line;
+            Direction::NorthWest
+        } else {
+            return Err(Error::at(
+                input,
+                line,
+                "Expected one of `e`, `w`, `se`, `sw`, `ne` or `nw`",
+            ));
+        };
+
+        pos = dir.step(pos);
+    }
+
+    Ok(pos)
+}
+
+fn flip(black_tiles: &mut HashSet<Position>, pos: Position) {
+    if !black_tiles.remove(&pos) {
+        black_tiles.insert(pos);
+    }
+}
+
+fn get_black_tiles(flipped_tiles: &[Position]) -> HashSet<Position> {
+    let mut black_tiles = HashSet::default();
+
+    for pos in flipped_tiles.iter().copied() {
+        flip(&mut black_tiles, pos);
+    }
+
+    black_tiles
+}
+
+/// Reads the flipped tiles line by line, keeping only the black tiles.
+#[cfg(feature = "std")]
+fn read_black_tiles(reader: impl BufRead) -> Result<HashSet<Position>> {
+    let mut lines = Lines::new(reader);
+    let mut black_tiles = HashSet::default();
+
+    while let Some(pos) = lines.parse_next(|line| parse_line(line, line))? {
+        flip(&mut black_tiles, pos);
+    }
+
+    Ok(black_tiles)
+}
+
+fn count_black_tiles(flipped_tiles: &[Position]) -> usize {
+    let black_tiles = get_black_tiles(flipped_tiles);
+
+    black_tiles.len()
+}
+
+fn simulate_days(
+    black_tiles: HashSet<Position>,
+    days: usize,
+    observer: &mut impl Observer<HashSet<Position>>,
+) -> Result<usize> {
+    let mut automaton = Sparse::new(black_tiles);
+
+    for _ in 0..days {
+        automaton.step(
+            |pos| ALL_DIRECTIONS.map(|dir| dir.step(pos)),
+            |black, black_neighbor_count| {
+                black_neighbor_count == 2 || black && black_neighbor_count == 1
+            },
+        );
+        notify(observer, automaton.active())?;
+    }
+
+    Ok(automaton.active_count())
+}
+
+fn count_black_tiles_after_days(
+    flipped_tiles: &[Position],
+    days: usize,
+    observer: &mut impl Observer<HashSet<Position>>,
+) -> Result<usize> {
+    simulate_days(get_black_tiles(flipped_tiles), days, observer)
+}
+
+pub fn part1(input: &str) -> Result<usize> {
+    Ok(count_black_tiles(&Solution::parse(&Text::new(input))?))
+}
+
+pub fn part2(input: &str) -> Result<usize> {
+    part2_with(input, &Params::default())
+}
+
+pub fn part2_with(input: &str, params: &Params) -> Result<usize> {
+    part2_observed(input, params, &mut Ignore)
+}
+
+/// Like [`part2_with`], but passes the black tiles to `observer` after each day.
+pub fn part2_observed(
+    input: &str,
+    params: &Params,
+    observer: &mut impl Observer<HashSet<Position>>,
+) -> Result<usize> {
+    count_black_tiles_after_days(&Solution::parse(&Text::new(input))?, params.days, observer)
+}
+
+/// Like [`part1`], but reads the input line by line, keeping only the black tiles.
+#[cfg(feature = "std")]
+pub fn part1_from_reader(reader: impl BufRead) -> Result<usize> {
+    Ok(read_black_tiles(reader)?.len())
+}
+
+/// Like [`part2`], but reads the input line by line, keeping only the black tiles.
+#[cfg(feature = "std")]
+pub fn part2_from_reader(reader: impl BufRead) -> Result<usize> {
+    part2_from_reader_with(reader, &Params::default())
+}
+
+/// Like [`part2_with`], but reads the input line by line, keeping only the black tiles.
+#[cfg(feature = "std")]
+pub fn part2_from_reader_with(reader: impl BufRead, params: &Params) -> Result<usize> {
+    simulate_days(read_black_tiles(reader)?, params.days, &mut Ignore)
+}
+
+/// Generates directions that often lead to the same tile more than once.
+pub fn generate(rng: &mut Rng) -> String {
+    let directions = ["e", "w", "se", "sw", "ne", "nw"];
+    let mut lines: Vec<Vec<&str>> = Vec::new();
+
+    for _ in 0..rng.range(1..60) {
+        let line = if !lines.is_empty() && rng.chance(0.2) {
+            let mut line = rng.choose(&lines).clone();
+            rng.shuffle(&mut line);
+            line
+        } else {
+            (0..rng.range(1..12))
+                .map(|_| *rng.choose(&directions))
+                .collect()
+        };
+        lines.push(line);
+    }
+
+    lines.iter().map(|line| line.concat()).join("\n")
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = Vec<Position>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse<'a>(input: &'a Text) -> Result<Self::Input<'a>> {
+        input.lines().map(|line| parse_line(input, line)).collect()
+    }
+
+    fn part1(flipped_tiles: &Self::Input<'_>) -> Result<Self::Answer1> {
+        Ok(count_black_tiles(flipped_tiles))
+    }
+
+    fn part2(flipped_tiles: &Self::Input<'_>) -> Option<Result<Self::Answer2>> {
+        Some(count_black_tiles_after_days(
+            flipped_tiles,
+            DAYS,
+            &mut Ignore,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::observer::Trace;
+    use crate::text::resaved;
+    use std::{collections::HashMap, ops::ControlFlow};
+
+    static TEST_INPUT: &str = include_str!("test_input.txt");
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(part1(TEST_INPUT), Ok(10));
+    }
+
+    #[test]
+    fn part2_works() {
+        assert_eq!(part2(TEST_INPUT), Ok(2208));
+    }
+
+    #[test]
+    fn resaved_input_works() {
+        for input in &resaved(TEST_INPUT) {
+            assert_eq!(part1(input), part1(TEST_INPUT));
+            assert_eq!(part2(input), part2(TEST_INPUT));
+        }
+    }
+
+    #[test]
+    fn params_change_the_days() {
+        assert_eq!(part2_with(TEST_INPUT, &Params { days: 0 }), Ok(10));
+        assert_eq!(part2_with(TEST_INPUT, &Params { days: 1 }), Ok(15));
+        assert_eq!(part2_with(TEST_INPUT, &Params { days: 10 }), Ok(37));
+    }
+
+    #[test]
+    fn observer_sees_each_day() {
+        let mut trace = Trace::new();
+        assert_eq!(
+            part2_observed(TEST_INPUT, &Params { days: 10 }, &mut trace),
+            Ok(37)
+        );
+        assert_eq!(
+            trace.states.iter().map(HashSet::len).collect_vec(),
+            [15, 12, 25, 14, 23, 28, 41, 37, 49, 37]
+        );
+
+        let mut days = 0;
+        let mut stop_after_5 = |_: &HashSet<Position>| {
+            days += 1;
+            if days < 5 {
+                ControlFlow::Continue(())
+            } else {
+                ControlFlow::Break(())
+            }
+        };
+        assert_eq!(
+            part2_observed(TEST_INPUT, &Params::default(), &mut stop_after_5),
+            Err(Error::Stopped)
+        );
+        assert_eq!(days, 5);
+    }
+
+    #[test]
+    fn invalid_input_is_reported() {
+        assert_eq!(
+            part1("esew\nnwwswxee\n"),
+            Err(Error::parse(
+                2,
+                6,
+                "Expected one of `e`, `w`, `se`, `sw`, `ne` or `nw`"
+            ))
+        );
+    }
+
+    #[test]
+    fn long_lines_work() {
+        let input = "e".repeat(40_000) + "\n" + &"w".repeat(40_000);
+        assert_eq!(part1(&input), Ok(2));
+        assert_eq!(part2(&input), Ok(0));
+        #[cfg(feature = "std")]
+        assert_eq!(part1_from_reader(input.as_bytes()), Ok(2));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reader_gives_same_results() {
+        for input in &resaved(TEST_INPUT) {
+            assert_eq!(part1_from_reader(input.as_bytes()), part1(input));
+            assert_eq!(part2_from_reader(input.as_bytes()), part2(input));
+        }
+        for input in [TEST_INPUT, "esew\nnwwswxee\n", "esew\n\nwe\n"] {
+            assert_eq!(part1_from_reader(input.as_bytes()), part1(input));
+            assert_eq!(part2_from_reader(input.as_bytes()), part2(input));
+        }
+        for seed in 0..5 {
+            let input = generate(&mut Rng::new(seed));
+            assert_eq!(part1_from_reader(input.as_bytes()), part1(&input));
+        }
+
+        let params = Params { days: 10 };
+        assert_eq!(
+            part2_from_reader_with(TEST_INPUT.as_bytes(), &params),
+            part2_with(TEST_INPUT, &params)
+        );
+    }
+
+    /// Flips tiles on cube coordinates, then lives the days by counting neighbors in a map.
+    fn reference_black_tiles(input: &str, days: usize) -> usize {
+        let mut black = HashSet::default();
+
+        for line in input.lines() {
+            let (mut x, mut y, mut z) = (0i32, 0i32, 0i32);
+            let mut rest = line;
+            while !rest.is_empty() {
+                let step = if rest.starts_with('e') || rest.starts_with('w') {
+                    1
+                } else {
+                    2
+                };
+                let (dx, dy, dz) = match &rest[..step] {
+                    "e" => (1, -1, 0),
+                    "w" => (-1, 1, 0),
+                    "ne" => (1, 0, -1),
+                    "sw" => (-1, 0, 1),
+                    "nw" => (0, 1, -1),
+                    "se" => (0, -1, 1),
+                    _ => unreachable!(),
+                };
+                (x, y, z) = (x + dx, y + dy, z + dz);
+                rest = &rest[step..];
+            }
+            if !black.remove(&(x, y, z)) {
+                black.insert((x, y, z));
+            }
+        }
+
+        for _ in 0..days {
+            let mut counts = HashMap::new();
+            for (x, y, z) in &black {
+                for (dx, dy, dz) in [
+                    (1, -1, 0),
+                    (-1, 1, 0),
+                    (1, 0, -1),
+                    (-1, 0, 1),
+                    (0, 1, -1),
+                    (0, -1, 1),
+                ] {
+                    *counts.entry((x + dx, y + dy, z + dz)).or_insert(0) += 1;
+                }
+            }
+            black = counts
+                .into_iter()
+                .filter(|(tile, count)| *count == 2 || *count == 1 && black.contains(tile))
+                .map(|(tile, _)| tile)
+                .collect();
+        }
+
+        black.len()
+    }
+
+    #[test]
+    fn random_inputs_match_reference() {
+        for seed in 0..5 {
+            let input = generate(&mut Rng::new(seed));
+            assert_eq!(
+                part1(&input),
+                Ok(reference_black_tiles(&input, 0)),
+                "seed {seed}"
+            );
+            assert_eq!(
+                part2(&input),
+                Ok(reference_black_tiles(&input, 100)),
+                "seed {seed}"
+            );
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use alloc::{format, string::String};
+
+use crate::{
+    error::parse_at,
+    modular::{discrete_log, pow_mod},
+    random::Rng,
+    solver::Solver,
+    text::Text,
+    Error, Result,
+};
+
+const MODULUS: u64 = 20201227;
+const SUBJECT_NUMBER: u64 = 7;
+
+/// Puzzle constants, defaulting to those of the actual puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    /// The modulus of the handshake, which must be positive. There is no solution for moduli
+    /// above [`modular::MAX_DISCRETE_LOG_MODULUS`](crate::modular::MAX_DISCRETE_LOG_MODULUS).
+    pub modulus: u64,
+    /// The subject number that the public keys are transformed from.
+    pub subject_number: u64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            modulus: MODULUS,
+            subject_number: SUBJECT_NUMBER,
+        }
+    }
+}
+
+pub fn part1(door_public_key: u64, card_public_key: u64) -> Result<u64> {
+    part1_with(door_public_key, card_public_key, &Params::default())
+}
+
+pub fn part1_with(door_public_key: u64, card_public_key: u64, params: &Params) -> Result<u64> {
+    let (modulus, subject_number) = (params.modulus, params.subject_number);
+    // Handshakes give keys below the modulus, which are never 0 for a subject number coprime to it
+    if ![door_public_key, card_public_key]
+        .iter()
+        .all(|public_key| (1..modulus).contains(public_key))
+    {
+        return Err(Error::NoSolution);
+    }
+    let loop_size = |public_key| discrete_log(subject_number, public_key, modulus);
+
+    // Either device's loop size gives the same key, so the smaller one is used
+    let (loop_size, other_public_key) =
+        match (loop_size(door_public_key), loop_size(card_public_key)) {
+            (Some(door), Some(card)) if card < door => (card, door_public_key),
+            (Some(door), _) => (door, card_public_key),
+            (None, Some(card)) => (card, door_public_key),
+            (None, None) => return Err(Error::NoSolution),
+        };
+
+    Ok(pow_mod(other_public_key, loop_size, modulus))
+}
+
+/// Generates the public keys for random loop sizes, returning the loop sizes along with the input.
+fn generate_with_loop_sizes(rng: &mut Rng) -> ((u64, u64), String) {
+    let loop_sizes = (rng.range(1..100_000) as u64, rng.range(1..100_000) as u64);
+
+    let public_key = |loop_size| (0..loop_size).fold(1, |key, _| key * SUBJECT_NUMBER % MODULUS);
+    let input = format!("{}\n{}", public_key(loop_sizes.0), public_key(loop_sizes.1));
+
+    (loop_sizes, input)
+}
+
+pub fn generate(rng: &mut Rng) -> String {
+    generate_with_loop_sizes(rng).1
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input<'a> = (u64, u64);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse<'a>(input: &'a Text) -> Result<Self::Input<'a>> {
+        let mut public_keys = input.lines().map(|line| parse_at(input, line.trim()));
+
+        let door_public_key = public_keys
+            .next()
+            .ok_or_else(|| Error::at_end(input, "Expected the door public key"))??;
+        let card_public_key = public_keys
+            .next()
+            .ok_or_else(|| Error::at_end(input, "Expected the card public key"))??;
+
+        Ok((door_public_key, card_public_key))
+    }
+
+    fn part1(&(door_public_key, card_public_key): &Self::Input<'_>) -> Result<Self::Answer1> {
+        part1(door_public_key, card_public_key)
+    }
+
+    fn part2(_: &Self::Input<'_>) -> Option<Result<Self::Answer2>> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::text::resaved;
+
+    static INPUT: &str = include_str!("input.txt");
+
+    #[test]
+    fn part1_works() {
+        assert_eq!(part1(5764801, 17807724), Ok(14897079));
+    }
+
+    #[test]
+    fn resaved_input_works() {
+        for input in &resaved(INPUT) {
+            assert_eq!(
+                Solution::parse(&Text::new(input)),
+                Solution::parse(&Text::new(INPUT))
+            );
+        }
+    }
+
+    #[test]
+    fn params_change_the_handshake() {
+        let params = Params {
+            modulus: 11,
+            subject_number: 2,
+        };
+        assert_eq!(part1_with(8, 5, &params), Ok(4));
+
+        // Products of keys below this modulus do not fit in a `u64`
+        let params = Params {
+            modulus: 1_000_000_000_039,
+            subject_number: 5,
+        };
+        let public_key = |loop_size| pow_mod(5, loop_size, params.modulus);
+        assert_eq!(
+            part1_with(public_key(1000), public_key(2000), &params),
+            Ok(public_key(1000 * 2000))
+        );
+
+        let params = Params {
+            modulus: crate::modular::MAX_DISCRETE_LOG_MODULUS + 1,
+            subject_number: 3,
+        };
+        assert_eq!(part1_with(3, 9, &params), Err(Error::NoSolution));
+    }
+
+    #[test]
+    fn invalid_input_is_reported() {
+        assert_eq!(
+            Solution::parse(&Text::new("5764801\n")),
+            Err(Error::parse(2, 1, "Expected the card public key"))
+        );
+        assert_eq!(part1(MODULUS, MODULUS), Err(Error::NoSolution));
+        assert_eq!(part1(MODULUS, 17807724), Err(Error::NoSolution));
+        assert_eq!(part1(5764801 + MODULUS, 17807724), Err(Error::NoSolution));
+        assert_eq!(part1(0, 17807724), Err(Error::NoSolution));
+    }
+
+    fn reference_pow(mut base: u64, mut exponent: u64) -> u64 {
+        let mut result = 1;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base % MODULUS;
+            }
+            base = base * base % MODULUS;
+            exponent >>= 1;
+        }
+        result
+    }
+
+    #[test]
+    fn random_inputs_match_reference() {
+        for seed in 0..20 {
+            let ((door_loop_size, card_loop_size), input) =
+                generate_with_loop_sizes(&mut Rng::new(seed));
+            let (door_public_key, card_public_key) = Solution::parse(&Text::new(&input)).unwrap();
+
+            let expected = reference_pow(door_public_key, card_loop_size);
+            assert_eq!(reference_pow(card_public_key, door_loop_size), expected);
+            assert_eq!(
+                part1(door_public_key, card_public_key),
+                Ok(expected),
+                "seed {seed}"
+            );
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use std::{
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    str,
+};
+
+use advent_of_code::{parallel::catch_panic, solver};
+
+/// Solves the inputs in `fuzz/regressions/<target>`, which once made the fuzz target of the day
+/// panic, the same way as the target does.
+#[test]
+fn fuzz_regressions_do_not_panic() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("fuzz")
+        .join("regressions");
+    let mut panics = Vec::new();
+
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    for puzzle in solver::puzzles() {
+        let target = format!("y{}_day{:02}", puzzle.year, puzzle.day);
+        let Ok(entries) = fs::read_dir(dir.join(target)) else {
+            continue;
+        };
+
+        for entry in entries {
+            let path = entry.unwrap().path();
+            let data = fs::read(&path).unwrap();
+            if let Ok(input) = str::from_utf8(&data) {
+                // The solvers hold no state that a panic could leave broken
+                let solve = AssertUnwindSafe(|| puzzle.solve_all(input));
+                if let Err(message) = catch_panic(solve) {
+                    panics.push(format!("{}: {message}", path.display()));
+                }
+            }
+        }
+    }
+
+    panic::set_hook(previous_hook);
+    assert!(panics.is_empty(), "{panics:#?}");
+}
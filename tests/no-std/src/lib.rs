@@ -0,0 +1,24 @@
+//! A `#![no_std]` crate using the solvers, which only builds if they work without `std`.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{string::String, vec::Vec};
+
+use advent_of_code::{
+    observer::Trace,
+    solver::{self, Part},
+    y2020::day11,
+    Result,
+};
+
+pub fn solve(year: u16, day: u8, part: Part, input: &str) -> Option<Result<String>> {
+    solver::solve(year, day, part, input)
+}
+
+pub fn seat_maps(input: &str) -> Result<Vec<day11::SeatMap>> {
+    let mut trace = Trace::new();
+    day11::part1_observed(input, &mut trace)?;
+    Ok(trace.states)
+}
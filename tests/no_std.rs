@@ -0,0 +1,40 @@
+use std::{path::Path, process::Command};
+
+/// Builds `tests/no-std`, a `#![no_std]` crate that uses the library without its default features.
+#[test]
+fn library_builds_without_std() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let manifest = root.join("tests").join("no-std").join("Cargo.toml");
+
+    let status = Command::new(env!("CARGO"))
+        .arg("build")
+        .arg("--manifest-path")
+        .arg(&manifest)
+        .arg("--target-dir")
+        .arg(root.join("target").join("no-std"))
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    // A dependency that pulls in `std` would still build, so check that none of them enable it
+    let output = Command::new(env!("CARGO"))
+        .args([
+            "tree",
+            "--edges",
+            "normal,features",
+            "--prefix",
+            "none",
+            "--manifest-path",
+        ])
+        .arg(&manifest)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let tree = String::from_utf8(output.stdout).unwrap();
+    let std_features: Vec<&str> = tree
+        .lines()
+        .filter(|line| line.contains(" feature \"std\"") || line.contains(" feature \"use_std\""))
+        .collect();
+    assert!(std_features.is_empty(), "{std_features:?}");
+}